@@ -0,0 +1,43 @@
+#![feature(test)]
+
+extern crate test;
+
+use math_rust::linalg::matrix::Matrix;
+use test::Bencher;
+
+// diagonally dominant so the values stay well scaled as the size grows
+fn matrix(size: usize) -> Matrix {
+    Matrix::from_fn(size, size, |i, j| {
+        let off_diagonal = ((i * 7 + j * 13) % 17) as f64 - 8.0;
+        if i == j {
+            off_diagonal + 10.0 * size as f64
+        } else {
+            off_diagonal
+        }
+    })
+}
+
+fn bench_determinant(b: &mut Bencher, size: usize) {
+    let m = matrix(size);
+    b.iter(|| test::black_box(&m).determinant().unwrap());
+}
+
+#[bench]
+fn determinant_10x10(b: &mut Bencher) {
+    bench_determinant(b, 10);
+}
+
+#[bench]
+fn determinant_50x50(b: &mut Bencher) {
+    bench_determinant(b, 50);
+}
+
+#[bench]
+fn determinant_100x100(b: &mut Bencher) {
+    bench_determinant(b, 100);
+}
+
+#[bench]
+fn determinant_200x200(b: &mut Bencher) {
+    bench_determinant(b, 200);
+}
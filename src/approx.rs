@@ -0,0 +1,258 @@
+use crate::week5::{scalar::Scalar, vector::Vector, vector_n::VectorN};
+use crate::week6::{
+    angle::{Degrees, Gradians, Radians, Turns},
+    dms::Dms,
+};
+use crate::week7::{
+    determinant::Value,
+    point::{Point2, Point3},
+    point_n::Point,
+};
+
+// two values are considered equal if they are within *any* of these tolerances
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    pub absolute: f64,
+    pub relative: f64,
+    pub ulps: u64,
+}
+
+impl Tolerance {
+    pub const DEFAULT: Tolerance = Tolerance {
+        absolute: 1e-9,
+        relative: 1e-9,
+        ulps: 4,
+    };
+
+    pub const EXACT: Tolerance = Tolerance {
+        absolute: 0.0,
+        relative: 0.0,
+        ulps: 0,
+    };
+
+    pub fn absolute(absolute: f64) -> Tolerance {
+        Tolerance {
+            absolute,
+            ..Tolerance::EXACT
+        }
+    }
+
+    pub fn relative(relative: f64) -> Tolerance {
+        Tolerance {
+            relative,
+            ..Tolerance::EXACT
+        }
+    }
+
+    pub fn ulps(ulps: u64) -> Tolerance {
+        Tolerance {
+            ulps,
+            ..Tolerance::EXACT
+        }
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance::DEFAULT
+    }
+}
+
+pub trait ApproxEq<Rhs: ?Sized = Self> {
+    fn approx_eq_with(&self, other: &Rhs, tolerance: Tolerance) -> bool;
+
+    fn approx_eq(&self, other: &Rhs) -> bool {
+        self.approx_eq_with(other, Tolerance::DEFAULT)
+    }
+}
+
+// maps the bits of a float onto integers that sort the same way the floats do,
+// so the difference between two of them is the number of representable values in between
+fn ordered_bits(x: f64) -> i64 {
+    let bits = x.to_bits() as i64;
+    if bits < 0 {
+        i64::MIN - bits
+    } else {
+        bits
+    }
+}
+
+pub fn ulps_between(a: f64, b: f64) -> u64 {
+    (ordered_bits(a) as i128 - ordered_bits(b) as i128)
+        .unsigned_abs()
+        .try_into()
+        .unwrap_or(u64::MAX)
+}
+
+impl ApproxEq for f64 {
+    fn approx_eq_with(&self, other: &f64, tolerance: Tolerance) -> bool {
+        if self == other {
+            return true;
+        }
+        if !self.is_finite() || !other.is_finite() {
+            return false;
+        }
+
+        let difference = (self - other).abs();
+        difference <= tolerance.absolute
+            || difference <= tolerance.relative * self.abs().max(other.abs())
+            || ulps_between(*self, *other) <= tolerance.ulps
+    }
+}
+
+impl ApproxEq for Scalar {
+    fn approx_eq_with(&self, other: &Scalar, tolerance: Tolerance) -> bool {
+        self.0.approx_eq_with(&other.0, tolerance)
+    }
+}
+
+impl ApproxEq for [f64] {
+    fn approx_eq_with(&self, other: &[f64], tolerance: Tolerance) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(x1, x2)| x1.approx_eq_with(x2, tolerance))
+    }
+}
+
+impl ApproxEq for Vector {
+    fn approx_eq_with(&self, other: &Vector, tolerance: Tolerance) -> bool {
+        self.0[..].approx_eq_with(&other.0[..], tolerance)
+    }
+}
+
+impl<const N: usize> ApproxEq for VectorN<N> {
+    fn approx_eq_with(&self, other: &VectorN<N>, tolerance: Tolerance) -> bool {
+        self.0[..].approx_eq_with(&other.0[..], tolerance)
+    }
+}
+
+impl ApproxEq for Point3 {
+    fn approx_eq_with(&self, other: &Point3, tolerance: Tolerance) -> bool {
+        [self.0, self.1, self.2][..].approx_eq_with(&[other.0, other.1, other.2][..], tolerance)
+    }
+}
+
+impl ApproxEq for Point2 {
+    fn approx_eq_with(&self, other: &Point2, tolerance: Tolerance) -> bool {
+        [self.0, self.1][..].approx_eq_with(&[other.0, other.1][..], tolerance)
+    }
+}
+
+impl<const N: usize> ApproxEq for Point<N> {
+    fn approx_eq_with(&self, other: &Point<N>, tolerance: Tolerance) -> bool {
+        self.0[..].approx_eq_with(&other.0[..], tolerance)
+    }
+}
+
+impl ApproxEq for Value {
+    fn approx_eq_with(&self, other: &Value, tolerance: Tolerance) -> bool {
+        match (self, other) {
+            (Value::Vector(lhs), Value::Vector(rhs)) => lhs.approx_eq_with(rhs, tolerance),
+            (Value::Scalar(lhs), Value::Scalar(rhs)) => lhs.approx_eq_with(rhs, tolerance),
+            _ => false,
+        }
+    }
+}
+
+impl ApproxEq for Radians {
+    fn approx_eq_with(&self, other: &Radians, tolerance: Tolerance) -> bool {
+        self.0.approx_eq_with(&other.0, tolerance)
+    }
+}
+
+impl ApproxEq for Degrees {
+    fn approx_eq_with(&self, other: &Degrees, tolerance: Tolerance) -> bool {
+        self.0.approx_eq_with(&other.0, tolerance)
+    }
+}
+
+impl ApproxEq for Gradians {
+    fn approx_eq_with(&self, other: &Gradians, tolerance: Tolerance) -> bool {
+        self.0.approx_eq_with(&other.0, tolerance)
+    }
+}
+
+impl ApproxEq for Turns {
+    fn approx_eq_with(&self, other: &Turns, tolerance: Tolerance) -> bool {
+        self.0.approx_eq_with(&other.0, tolerance)
+    }
+}
+
+impl ApproxEq for Dms {
+    fn approx_eq_with(&self, other: &Dms, tolerance: Tolerance) -> bool {
+        self.0.approx_eq_with(&other.0, tolerance)
+    }
+}
+
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, $crate::approx::Tolerance::DEFAULT)
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !$crate::approx::ApproxEq::approx_eq_with(left, right, $tolerance) {
+                    panic!(
+                        "assertion failed: `left ≈ right`\n  left: `{:?}`\n right: `{:?}`",
+                        left, right
+                    )
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_ne!($left, $right, $crate::approx::Tolerance::DEFAULT)
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if $crate::approx::ApproxEq::approx_eq_with(left, right, $tolerance) {
+                    panic!(
+                        "assertion failed: `left !≈ right`\n  left: `{:?}`\n right: `{:?}`",
+                        left, right
+                    )
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::week5::{scalar::Scalar, vector::Vector};
+
+    use super::{ulps_between, ApproxEq, Tolerance};
+
+    #[test]
+    fn floats() {
+        assert!((0.1 + 0.2).approx_eq(&0.3));
+        assert!(!(0.1 + 0.2).approx_eq_with(&0.3, Tolerance::EXACT));
+        assert!(1e20.approx_eq_with(&(1e20 + 1e6), Tolerance::relative(1e-12)));
+        assert!(!1e-20.approx_eq_with(&2e-20, Tolerance::relative(1e-12)));
+        assert!(!f64::NAN.approx_eq(&f64::NAN));
+        assert!(f64::INFINITY.approx_eq(&f64::INFINITY));
+    }
+
+    #[test]
+    fn ulps() {
+        assert_eq!(ulps_between(1.0, 1.0 + f64::EPSILON), 1);
+        assert_eq!(ulps_between(-0.0, 0.0), 0);
+        assert!(1.0.approx_eq_with(&(1.0 + 2.0 * f64::EPSILON), Tolerance::ulps(2)));
+        assert!(!1.0.approx_eq_with(&(1.0 + 3.0 * f64::EPSILON), Tolerance::ulps(2)));
+    }
+
+    #[test]
+    fn macros() {
+        assert_approx_eq!(Scalar(2.0_f64.sqrt() * 2.0_f64.sqrt()), Scalar(2.0));
+        assert_approx_eq!(Vector(vec![0.1 + 0.2]), Vector(vec![0.3]));
+        assert_approx_ne!(Vector(vec![1.0]), Vector(vec![1.0, 0.0]));
+        assert_approx_eq!(1.0, 1.1, Tolerance::absolute(0.2));
+    }
+}
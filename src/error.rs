@@ -0,0 +1,74 @@
+use std::{error::Error, fmt::Display};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MathError {
+    DimensionMismatch { left: usize, right: usize },
+    ZeroVector,
+    NonSquareMatrix { rows: usize, cols: usize },
+    // a flat list of entries that can't be laid out as an n x n matrix
+    NotASquareLength { length: usize },
+    UnsupportedDimension { expected: usize, found: usize },
+    SingularMatrix,
+    NotSymmetric,
+    NotPositiveDefinite,
+    NotEnoughPoints { needed: usize, found: usize },
+    // an affine combination whose weights don't add up to 1
+    UnnormalizedWeights { sum: f64 },
+    // direction cosines whose squares don't add up to 1
+    InvalidDirectionCosines { sum: f64 },
+    // a p-norm with p below 1 (or NaN), which breaks the triangle inequality
+    InvalidNormExponent { p: f64 },
+    // points that all lie on one line, where something needs them to span a plane
+    CollinearPoints,
+    // an iterative algorithm gave up before reaching the required precision
+    NoConvergence,
+    // a vector was combined with a scalar where only one kind makes sense
+    MismatchedValues,
+    // text that doesn't describe the expected kind of object
+    Parse(String),
+}
+
+impl Display for MathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MathError::DimensionMismatch { left, right } => {
+                write!(f, "mismatched dimensions: {} and {}", left, right)
+            }
+            MathError::ZeroVector => write!(f, "operation is undefined for the zero vector"),
+            MathError::NonSquareMatrix { rows, cols } => {
+                write!(f, "expected a square matrix, got {}x{}", rows, cols)
+            }
+            MathError::NotASquareLength { length } => {
+                write!(f, "{} entries can't form a square matrix", length)
+            }
+            MathError::UnsupportedDimension { expected, found } => {
+                write!(f, "expected dimension {}, got {}", expected, found)
+            }
+            MathError::SingularMatrix => write!(f, "matrix is singular"),
+            MathError::NotSymmetric => write!(f, "matrix is not symmetric"),
+            MathError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            MathError::NotEnoughPoints { needed, found } => {
+                write!(f, "need at least {} points, got {}", needed, found)
+            }
+            MathError::UnnormalizedWeights { sum } => {
+                write!(f, "weights add up to {} instead of 1", sum)
+            }
+            MathError::InvalidDirectionCosines { sum } => {
+                write!(
+                    f,
+                    "squared direction cosines add up to {} instead of 1",
+                    sum
+                )
+            }
+            MathError::InvalidNormExponent { p } => {
+                write!(f, "p-norms need p >= 1, got {}", p)
+            }
+            MathError::CollinearPoints => write!(f, "points are collinear"),
+            MathError::NoConvergence => write!(f, "iteration did not converge"),
+            MathError::MismatchedValues => write!(f, "cannot combine a vector with a scalar"),
+            MathError::Parse(reason) => write!(f, "could not parse: {}", reason),
+        }
+    }
+}
+
+impl Error for MathError {}
@@ -1,5 +1,9 @@
 #![feature(box_patterns)]
 
+#[macro_use]
+pub mod approx;
+pub mod error;
+pub mod linalg;
 pub mod week5;
 pub mod week6;
 pub mod week7;
@@ -0,0 +1,519 @@
+use std::ops::Mul;
+
+use crate::{
+    approx::{ApproxEq, Tolerance},
+    error::MathError,
+    week5::{vector::Vector, vector_n::VectorN},
+    week6::angle::Angle,
+    week7::{
+        line::Line3,
+        plane::Plane3,
+        point::{Point2, Point3},
+        point_n::Point,
+    },
+};
+
+use super::{matrix::Matrix, quaternion::Quaternion, rotation::Axis};
+
+// x -> Ax + b, kept as the (N + 1) x (N + 1) homogeneous matrix
+//   [A b]
+//   [0 1]
+// so points (with a 1 appended) are translated and vectors (with a 0 appended) are not
+#[derive(Debug, Clone, PartialEq)]
+pub struct Affine<const N: usize> {
+    matrix: Matrix,
+}
+
+pub type Affine2 = Affine<2>;
+pub type Affine3 = Affine<3>;
+
+// linear part = rotation * diag(scale) * shear
+#[derive(Debug, Clone)]
+pub struct Decomposition<const N: usize> {
+    pub translation: VectorN<N>,
+    // orthogonal with determinant 1
+    pub rotation: Matrix,
+    // a reflection shows up as a negative last entry
+    pub scale: VectorN<N>,
+    // upper triangular with ones on the diagonal
+    pub shear: Matrix,
+}
+
+impl<const N: usize> Affine<N> {
+    pub fn identity() -> Affine<N> {
+        Affine {
+            matrix: Matrix::identity(N + 1),
+        }
+    }
+
+    pub fn new(linear: &Matrix, translation: &Vector) -> Result<Affine<N>, MathError> {
+        if let Some(found) = [linear.rows(), linear.cols()].into_iter().find(|&d| d != N) {
+            return Err(MathError::DimensionMismatch {
+                left: N,
+                right: found,
+            });
+        }
+        let translation = VectorN::<N>::try_from(translation)?;
+        Ok(Affine {
+            matrix: Matrix::from_fn(N + 1, N + 1, |i, j| match (i < N, j < N) {
+                (true, true) => linear[(i, j)],
+                (true, false) => translation.0[i],
+                (false, true) => 0.0,
+                (false, false) => 1.0,
+            }),
+        })
+    }
+
+    pub fn from_linear(linear: &Matrix) -> Result<Affine<N>, MathError> {
+        Affine::new(linear, &VectorN::<N>::ZERO.into())
+    }
+
+    pub fn from_translation(offset: &Vector) -> Result<Affine<N>, MathError> {
+        Affine::new(&Matrix::identity(N), offset)
+    }
+
+    // about the origin, one factor per axis
+    pub fn from_scale(factors: [f64; N]) -> Affine<N> {
+        let linear = Matrix::from_fn(N, N, |i, j| if i == j { factors[i] } else { 0.0 });
+        Affine::from_linear(&linear).unwrap()
+    }
+
+    pub fn from_uniform_scale(factor: f64) -> Affine<N> {
+        Affine::from_scale([factor; N])
+    }
+
+    // adds factor times the source coordinate to the target coordinate,
+    // eg. (X, Y) gives x -> x + factor * y
+    pub fn from_shear(target: Axis, source: Axis, factor: f64) -> Result<Affine<N>, MathError> {
+        if let Some(axis) = [target, source].into_iter().find(|axis| axis.index() >= N) {
+            return Err(MathError::UnsupportedDimension {
+                expected: N,
+                found: axis.index() + 1,
+            });
+        }
+        let mut linear = Matrix::identity(N);
+        linear[(target.index(), source.index())] += factor;
+        Affine::from_linear(&linear)
+    }
+
+    // the homogeneous matrix
+    pub fn to_homogeneous(&self) -> Matrix {
+        self.matrix.clone()
+    }
+
+    // A
+    pub fn linear(&self) -> Matrix {
+        Matrix::from_fn(N, N, |i, j| self.matrix[(i, j)])
+    }
+
+    // b
+    pub fn translation(&self) -> VectorN<N> {
+        VectorN(std::array::from_fn(|i| self.matrix[(i, N)]))
+    }
+
+    // self first, then next
+    pub fn then(&self, next: &Affine<N>) -> Affine<N> {
+        next * self
+    }
+
+    pub fn inverse(&self) -> Result<Affine<N>, MathError> {
+        Ok(Affine {
+            matrix: self.matrix.inverse()?,
+        })
+    }
+
+    // via A = QR, with the signs chosen so that R has a positive diagonal
+    pub fn decompose(&self) -> Result<Decomposition<N>, MathError> {
+        let qr = self.linear().qr();
+        let (mut q, mut r) = (qr.q, qr.r);
+        // the same test as Lu::is_singular, relative to the largest diagonal entry
+        let largest = (0..N).map(|i| r[(i, i)].abs()).fold(0.0, f64::max);
+        let threshold = largest * N as f64 * f64::EPSILON;
+        for i in 0..N {
+            if r[(i, i)].abs() <= threshold {
+                return Err(MathError::SingularMatrix);
+            }
+            if r[(i, i)] < 0.0 {
+                flip_column_and_row(&mut q, &mut r, i);
+            }
+        }
+        if q.determinant()?.0 < 0.0 {
+            flip_column_and_row(&mut q, &mut r, N - 1);
+        }
+
+        let scale = VectorN(std::array::from_fn(|i| r[(i, i)]));
+        let shear = Matrix::from_fn(N, N, |i, j| r[(i, j)] / scale.0[i]);
+        Ok(Decomposition {
+            translation: self.translation(),
+            rotation: q,
+            scale,
+            shear,
+        })
+    }
+
+    fn transform_homogeneous(&self, x: &[f64; N], w: f64) -> [f64; N] {
+        std::array::from_fn(|i| {
+            (0..N).map(|j| self.matrix[(i, j)] * x[j]).sum::<f64>() + self.matrix[(i, N)] * w
+        })
+    }
+}
+
+// keeps Q R the same while negating column i of Q and row i of R
+fn flip_column_and_row(q: &mut Matrix, r: &mut Matrix, i: usize) {
+    for k in 0..q.rows() {
+        q[(k, i)] = -q[(k, i)];
+    }
+    for k in 0..r.cols() {
+        r[(i, k)] = -r[(i, k)];
+    }
+}
+
+impl<const N: usize> Decomposition<N> {
+    pub fn recomposed(&self) -> Affine<N> {
+        let scale = Affine::<N>::from_scale(self.scale.0).linear();
+        let linear = &(&self.rotation * &scale) * &self.shear;
+        Affine::new(&linear, &self.translation.into()).unwrap()
+    }
+}
+
+impl Affine2 {
+    // about the origin
+    pub fn from_rotation(angle: impl Angle) -> Affine2 {
+        Affine::from_linear(&Matrix::rotation_2d(angle)).unwrap()
+    }
+
+    pub fn from_rotation_about(center: Point2, angle: impl Angle) -> Affine2 {
+        let to_origin = Affine::from_translation(&(Point2::ZERO - center)).unwrap();
+        let back = Affine::from_translation(&center.to_vector()).unwrap();
+        to_origin.then(&Affine2::from_rotation(angle)).then(&back)
+    }
+
+    // across the line through the origin with this direction
+    pub fn from_reflection(direction: &Vector) -> Result<Affine2, MathError> {
+        let u = VectorN::<2>::try_from(direction)?;
+        if u.magnitude().0 == 0.0 {
+            return Err(MathError::ZeroVector);
+        }
+        let u = u.unit_in_direction().0;
+        // 2uu^T - I
+        let identity = Matrix::identity(2);
+        let linear = Matrix::from_fn(2, 2, |i, j| 2.0 * u[i] * u[j] - identity[(i, j)]);
+        Affine::from_linear(&linear)
+    }
+}
+
+impl Affine3 {
+    // about an axis through the origin
+    pub fn from_rotation(axis: &Vector, angle: impl Angle) -> Result<Affine3, MathError> {
+        Affine::from_linear(&Matrix::rotation_about_axis(axis, angle)?)
+    }
+
+    pub fn from_rotation_about_line(line: &Line3, angle: impl Angle) -> Affine3 {
+        let to_origin = Affine::from_translation(&(Point3::ZERO - line.point)).unwrap();
+        let back = Affine::from_translation(&line.point.to_vector()).unwrap();
+        let rotation = Affine3::from_rotation(&line.direction, angle).unwrap();
+        to_origin.then(&rotation).then(&back)
+    }
+
+    pub fn from_quaternion(rotation: &Quaternion) -> Result<Affine3, MathError> {
+        Affine::from_linear(&rotation.to_rotation_matrix()?)
+    }
+
+    // the plane's normal doesn't have to be unit length, it's normalized here
+    pub fn from_reflection(plane: &Plane3) -> Result<Affine3, MathError> {
+        if plane.normal.0.len() != 3 {
+            return Err(MathError::UnsupportedDimension {
+                expected: 3,
+                found: plane.normal.0.len(),
+            });
+        }
+        let normal = plane.normal.try_unit()?;
+        let n = &normal.0;
+        // x - 2((x - p) . n)n = (I - 2nn^T)x + 2(p . n)n
+        let identity = Matrix::identity(3);
+        let linear = Matrix::from_fn(3, 3, |i, j| identity[(i, j)] - 2.0 * n[i] * n[j]);
+        let offset = normal.multiplied(plane.point.to_vector().dotted(&normal) * 2.0);
+        Affine::new(&linear, &offset)
+    }
+}
+
+// a * b is b followed by a, the same as for matrices
+impl<const N: usize> Mul<&Affine<N>> for &Affine<N> {
+    type Output = Affine<N>;
+
+    fn mul(self, rhs: &Affine<N>) -> Self::Output {
+        Affine {
+            matrix: &self.matrix * &rhs.matrix,
+        }
+    }
+}
+
+impl<const N: usize> Mul for Affine<N> {
+    type Output = Affine<N>;
+
+    fn mul(self, rhs: Affine<N>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<const N: usize> ApproxEq for Affine<N> {
+    fn approx_eq_with(&self, other: &Affine<N>, tolerance: Tolerance) -> bool {
+        self.matrix.approx_eq_with(&other.matrix, tolerance)
+    }
+}
+
+// ========== acting on things ==========
+
+pub trait Transform<T> {
+    type Output;
+
+    fn transform(&self, target: &T) -> Self::Output;
+}
+
+impl<const N: usize> Transform<Point<N>> for Affine<N> {
+    type Output = Point<N>;
+
+    fn transform(&self, point: &Point<N>) -> Point<N> {
+        Point(self.transform_homogeneous(&point.0, 1.0))
+    }
+}
+
+impl<const N: usize> Transform<VectorN<N>> for Affine<N> {
+    type Output = VectorN<N>;
+
+    fn transform(&self, vector: &VectorN<N>) -> VectorN<N> {
+        VectorN(self.transform_homogeneous(&vector.0, 0.0))
+    }
+}
+
+impl<const N: usize> Transform<Vector> for Affine<N> {
+    type Output = Result<Vector, MathError>;
+
+    fn transform(&self, vector: &Vector) -> Result<Vector, MathError> {
+        Ok(self.transform(&VectorN::<N>::try_from(vector)?).into())
+    }
+}
+
+impl Transform<Point2> for Affine2 {
+    type Output = Point2;
+
+    fn transform(&self, point: &Point2) -> Point2 {
+        self.transform(&Point::from(*point)).into()
+    }
+}
+
+impl Transform<Point3> for Affine3 {
+    type Output = Point3;
+
+    fn transform(&self, point: &Point3) -> Point3 {
+        self.transform(&Point::from(*point)).into()
+    }
+}
+
+// normals go through the inverse transpose: they have to stay perpendicular to every vector in
+// the plane, and A doesn't preserve angles unless it is a rotation
+impl Transform<Plane3> for Affine3 {
+    type Output = Result<Plane3, MathError>;
+
+    fn transform(&self, plane: &Plane3) -> Result<Plane3, MathError> {
+        let normal = self.linear().inverse()?.transposed().apply(&plane.normal)?;
+        Plane3::try_from_point_and_normal(self.transform(&plane.point), normal)
+    }
+}
+
+// fails when the direction is squashed down to zero
+impl Transform<Line3> for Affine3 {
+    type Output = Result<Line3, MathError>;
+
+    fn transform(&self, line: &Line3) -> Result<Line3, MathError> {
+        Line3::from_point_and_direction(
+            self.transform(&line.point),
+            self.transform(&line.direction)?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::MathError,
+        linalg::{matrix::Matrix, quaternion::Quaternion, rotation::Axis},
+        week5::{vector::Vector, vector_n::VectorN},
+        week6::angle::Degrees,
+        week7::{
+            line::Line3,
+            plane::Plane3,
+            point::{Point2, Point3},
+        },
+    };
+
+    use super::{Affine, Affine2, Affine3, Transform};
+
+    #[test]
+    fn points_and_vectors() {
+        let shift = Affine3::from_translation(&Vector(vec![1.0, 2.0, 3.0])).unwrap();
+
+        assert_eq!(shift.transform(&Point3::ZERO), Point3(1.0, 2.0, 3.0));
+        assert_eq!(
+            shift.transform(&Vector(vec![1.0, 1.0, 1.0])),
+            Ok(Vector(vec![1.0, 1.0, 1.0]))
+        );
+        assert_eq!(
+            shift.transform(&Vector(vec![1.0, 1.0])),
+            Err(MathError::DimensionMismatch { left: 3, right: 2 })
+        );
+
+        let turn = Affine2::from_rotation_about(Point2(1.0, 1.0), Degrees(90.0));
+        assert_approx_eq!(turn.transform(&Point2(2.0, 1.0)), Point2(1.0, 2.0));
+        assert_approx_eq!(turn.transform(&VectorN([1.0, 0.0])), VectorN([0.0, 1.0]));
+
+        let sheared = Affine2::from_shear(Axis::X, Axis::Y, 2.0).unwrap();
+        assert_eq!(sheared.transform(&Point2(1.0, 1.0)), Point2(3.0, 1.0));
+        assert_eq!(
+            Affine2::from_shear(Axis::X, Axis::Z, 2.0),
+            Err(MathError::UnsupportedDimension {
+                expected: 2,
+                found: 3
+            })
+        );
+
+        let mirror = Affine2::from_reflection(&Vector(vec![1.0, 1.0])).unwrap();
+        assert_approx_eq!(mirror.transform(&Point2(1.0, 0.0)), Point2(0.0, 1.0));
+    }
+
+    #[test]
+    fn composition_and_inverse() {
+        let scale = Affine3::from_scale([2.0, 1.0, 3.0]);
+        let shift = Affine3::from_translation(&Vector(vec![0.0, 0.0, 1.0])).unwrap();
+        let p = Point3(1.0, 1.0, 1.0);
+
+        assert_eq!(scale.then(&shift).transform(&p), Point3(2.0, 1.0, 4.0));
+        assert_eq!((&scale * &shift).transform(&p), Point3(2.0, 1.0, 6.0));
+        assert_eq!(
+            (&shift * &scale),
+            Affine3::new(&scale.linear(), &Vector(vec![0.0, 0.0, 1.0])).unwrap()
+        );
+
+        let both = scale.then(&shift);
+        assert_approx_eq!(both.then(&both.inverse().unwrap()), Affine3::identity());
+        assert_eq!(
+            Affine3::from_scale([1.0, 0.0, 1.0]).inverse(),
+            Err(MathError::SingularMatrix)
+        );
+    }
+
+    #[test]
+    fn planes_and_lines() {
+        // x + y = 1, stretched along x
+        let plane = Plane3::from_equation(1.0, 1.0, 0.0, 1.0);
+        let stretch = Affine3::from_scale([2.0, 1.0, 1.0]);
+        let stretched = stretch.transform(&plane).unwrap();
+
+        assert_eq!(stretched, Plane3::from_equation(1.0, 2.0, 0.0, 2.0));
+        for p in [
+            Point3(1.0, 0.0, 0.0),
+            Point3(0.0, 1.0, 5.0),
+            Point3(0.5, 0.5, 0.0),
+        ] {
+            assert!(stretched.contains(&stretch.transform(&p)));
+        }
+
+        let line = Line3::from_points(Point3::ZERO, Point3(1.0, 1.0, 0.0)).unwrap();
+        let shift = Affine3::from_translation(&Vector(vec![0.0, 0.0, 1.0])).unwrap();
+        assert_eq!(
+            shift.then(&stretch).transform(&line),
+            Line3::from_points(Point3(0.0, 0.0, 1.0), Point3(2.0, 1.0, 1.0))
+        );
+        assert_eq!(
+            Affine3::from_scale([0.0, 0.0, 1.0]).transform(&line),
+            Err(MathError::ZeroVector)
+        );
+
+        // reflecting the point across its own mirror plane twice gets it back
+        let mirror = Affine3::from_reflection(&Plane3::from_equation(0.0, 0.0, 1.0, 2.0)).unwrap();
+        assert_approx_eq!(
+            mirror.transform(&Point3(1.0, 1.0, 0.0)),
+            Point3(1.0, 1.0, 4.0)
+        );
+        assert_approx_eq!(mirror.then(&mirror), Affine3::identity());
+
+        // the same plane z = 2, written with a normal that isn't unit length
+        let long_normal = Plane3 {
+            point: Point3(5.0, -1.0, 2.0),
+            normal: Vector(vec![0.0, 0.0, 2.0]),
+        };
+        assert_approx_eq!(Affine3::from_reflection(&long_normal).unwrap(), mirror);
+        let flat = Plane3 {
+            point: Point3::ZERO,
+            normal: Vector(vec![0.0, 1.0]),
+        };
+        assert_eq!(
+            Affine3::from_reflection(&flat),
+            Err(MathError::UnsupportedDimension {
+                expected: 3,
+                found: 2
+            })
+        );
+        let degenerate = Plane3 {
+            point: Point3::ZERO,
+            normal: Vector(vec![0.0, 0.0, 0.0]),
+        };
+        assert_eq!(
+            Affine3::from_reflection(&degenerate),
+            Err(MathError::ZeroVector)
+        );
+    }
+
+    #[test]
+    fn rotations() {
+        let axis = Line3::from_points(Point3(1.0, 0.0, 0.0), Point3(1.0, 0.0, 1.0)).unwrap();
+        let turn = Affine3::from_rotation_about_line(&axis, Degrees(90.0));
+        assert_approx_eq!(
+            turn.transform(&Point3(2.0, 0.0, 3.0)),
+            Point3(1.0, 1.0, 3.0)
+        );
+
+        let q = Quaternion::from_axis_angle(&Vector(vec![0.0, 0.0, 1.0]), Degrees(90.0)).unwrap();
+        assert_approx_eq!(
+            Affine3::from_quaternion(&q).unwrap(),
+            Affine3::from_rotation(&Vector(vec![0.0, 0.0, 1.0]), Degrees(90.0)).unwrap()
+        );
+    }
+
+    #[test]
+    fn decomposition() {
+        let rotation = Affine3::from_rotation(&Vector(vec![1.0, 2.0, 2.0]), Degrees(40.0)).unwrap();
+        let shift = Affine3::from_translation(&Vector(vec![3.0, -1.0, 2.0])).unwrap();
+        let transform = Affine3::from_scale([2.0, 3.0, 0.5])
+            .then(&rotation)
+            .then(&shift);
+
+        let parts = transform.decompose().unwrap();
+        assert_approx_eq!(parts.translation, VectorN([3.0, -1.0, 2.0]));
+        assert_approx_eq!(parts.rotation, rotation.linear());
+        assert_approx_eq!(parts.scale, VectorN([2.0, 3.0, 0.5]));
+        assert_approx_eq!(parts.shear, Matrix::identity(3));
+        assert_approx_eq!(parts.recomposed(), transform);
+
+        // a reflection ends up in the scale, the rotation stays proper
+        let flipped = Affine2::from_scale([1.0, -2.0]).then(&Affine2::from_rotation(Degrees(30.0)));
+        let parts = flipped.decompose().unwrap();
+        assert_approx_eq!(parts.rotation.determinant().unwrap().0, 1.0);
+        assert_approx_eq!(parts.recomposed(), flipped);
+
+        let sheared = Affine::<3>::from_shear(Axis::X, Axis::Z, 0.5).unwrap();
+        assert_approx_eq!(sheared.decompose().unwrap().shear, sheared.linear());
+        assert!(matches!(
+            Affine3::from_scale([1.0, 0.0, 1.0]).decompose(),
+            Err(MathError::SingularMatrix)
+        ));
+        // squashed flat as far as rounding can tell, which would give inf and NaN factors
+        assert!(matches!(
+            Affine3::from_scale([1.0, 1e-300, 1.0]).decompose(),
+            Err(MathError::SingularMatrix)
+        ));
+        // uniformly small is fine though
+        let tiny = Affine3::from_uniform_scale(1e-300).decompose().unwrap();
+        assert_approx_eq!(tiny.shear, Matrix::identity(3));
+    }
+}
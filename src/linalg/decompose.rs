@@ -0,0 +1,319 @@
+use crate::error::MathError;
+use crate::week5::{scalar::Scalar, vector::Vector};
+
+use super::matrix::Matrix;
+
+// A = QR with Q orthogonal (m x m) and R upper triangular (m x n)
+#[derive(Debug, Clone)]
+pub struct Qr {
+    pub q: Matrix,
+    pub r: Matrix,
+}
+
+// A = U diag(singular_values) V^T, keeping only min(m, n) singular values
+#[derive(Debug, Clone)]
+pub struct Svd {
+    // m x k, orthonormal columns (zero for zero singular values)
+    pub u: Matrix,
+    // k values, largest first
+    pub singular_values: Vec<f64>,
+    // n x k, orthonormal columns
+    pub v: Matrix,
+}
+
+impl Svd {
+    pub fn rank(&self) -> usize {
+        let largest = self.singular_values.first().copied().unwrap_or(0.0);
+        let threshold = largest * self.u.rows().max(self.v.rows()) as f64 * f64::EPSILON;
+        self.singular_values
+            .iter()
+            .filter(|&&s| s > threshold)
+            .count()
+    }
+
+    pub fn reconstructed(&self) -> Matrix {
+        let k = self.singular_values.len();
+        let sigma = Matrix::from_fn(
+            k,
+            k,
+            |i, j| {
+                if i == j {
+                    self.singular_values[i]
+                } else {
+                    0.0
+                }
+            },
+        );
+        &(&self.u * &sigma) * &self.v.transposed()
+    }
+}
+
+const MAX_SWEEPS: usize = 100;
+
+impl Matrix {
+    // householder reflections, one per column
+    pub fn qr(&self) -> Qr {
+        let (m, n) = (self.rows(), self.cols());
+        let mut q = Matrix::identity(m);
+        let mut r = self.clone();
+
+        for k in 0..n.min(m.saturating_sub(1)) {
+            let x = Vector((k..m).map(|i| r[(i, k)]).collect());
+            let norm = x.magnitude().0;
+            if norm == 0.0 {
+                continue;
+            }
+
+            // reflect x onto -sign(x0)|x| e1, the choice that avoids cancellation
+            let alpha = -norm.copysign(x.0[0]);
+            let mut v = x;
+            v.0[0] -= alpha;
+            let v = v.into_unit_in_direction();
+
+            // R -> H R, only rows k.. change
+            for j in k..n {
+                let column = Vector((k..m).map(|i| r[(i, j)]).collect());
+                let projection = 2.0 * v.dotted(&column).0;
+                for i in k..m {
+                    r[(i, j)] -= projection * v.0[i - k];
+                }
+            }
+            // Q -> Q H, only columns k.. change
+            for i in 0..m {
+                let row = Vector(q.row(i)[k..].to_vec());
+                let projection = 2.0 * v.dotted(&row).0;
+                for j in k..m {
+                    q[(i, j)] -= projection * v.0[j - k];
+                }
+            }
+
+            r[(k, k)] = alpha;
+            for i in (k + 1)..m {
+                r[(i, k)] = 0.0;
+            }
+        }
+
+        Qr { q, r }
+    }
+
+    // A = L L^T with L lower triangular
+    pub fn cholesky(&self) -> Result<Matrix, MathError> {
+        self.require_square()?;
+        if !self.is_symmetric() {
+            return Err(MathError::NotSymmetric);
+        }
+
+        let size = self.rows();
+        let mut l = Matrix::zero(size, size);
+        for j in 0..size {
+            let diagonal = self[(j, j)] - (0..j).map(|k| l[(j, k)] * l[(j, k)]).sum::<f64>();
+            if diagonal <= 0.0 {
+                return Err(MathError::NotPositiveDefinite);
+            }
+            l[(j, j)] = diagonal.sqrt();
+
+            for i in (j + 1)..size {
+                l[(i, j)] =
+                    (self[(i, j)] - (0..j).map(|k| l[(i, k)] * l[(j, k)]).sum::<f64>()) / l[(j, j)];
+            }
+        }
+
+        Ok(l)
+    }
+
+    // one-sided jacobi: rotate pairs of columns until they're all orthogonal
+    pub fn svd(&self) -> Result<Svd, MathError> {
+        if self.rows() < self.cols() {
+            let Svd {
+                u,
+                singular_values,
+                v,
+            } = self.transposed().svd()?;
+            return Ok(Svd {
+                u: v,
+                singular_values,
+                v: u,
+            });
+        }
+
+        let n = self.cols();
+        let mut columns = self.column_vectors();
+        let mut v = Matrix::identity(n).column_vectors();
+
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let alpha = columns[p].magnitude_squared().0;
+                    let beta = columns[q].magnitude_squared().0;
+                    let gamma = columns[p].dotted(&columns[q]).0;
+                    if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (2.0 * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                    let c = 1.0 / (1.0 + t * t).sqrt();
+                    let s = c * t;
+
+                    for vectors in [&mut columns, &mut v] {
+                        let (vp, vq) = (vectors[p].clone(), vectors[q].clone());
+                        vectors[p] = vp.multiplied(Scalar(c)) - vq.multiplied(Scalar(s));
+                        vectors[q] = vp.multiplied(Scalar(s)) + vq.multiplied(Scalar(c));
+                    }
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(MathError::NoConvergence);
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        let norms: Vec<f64> = columns.iter().map(|c| c.magnitude().0).collect();
+        order.sort_by(|&a, &b| norms[b].total_cmp(&norms[a]));
+
+        let singular_values = order.iter().map(|&i| norms[i]).collect();
+        let u = order
+            .iter()
+            .map(|&i| {
+                if norms[i] == 0.0 {
+                    columns[i].clone()
+                } else {
+                    columns[i].multiplied(Scalar(1.0 / norms[i]))
+                }
+            })
+            .collect();
+        let v = order.iter().map(|&i| v[i].clone()).collect();
+
+        Ok(Svd {
+            u: Matrix::from_columns(u)?,
+            singular_values,
+            v: Matrix::from_columns(v)?,
+        })
+    }
+}
+
+// x minimizing |Ax - b|, through the QR decomposition of A
+pub fn solve_least_squares(a: &Matrix, b: &Vector) -> Result<Vector, MathError> {
+    if a.rows() != b.0.len() {
+        return Err(MathError::DimensionMismatch {
+            left: a.rows(),
+            right: b.0.len(),
+        });
+    }
+    if a.rows() < a.cols() {
+        // fewer equations than unknowns, the columns can't be independent
+        return Err(MathError::SingularMatrix);
+    }
+
+    let Qr { q, r } = a.qr();
+    let n = a.cols();
+    // R x = Q^T b, using only the top n rows
+    let rhs = q.transposed().apply(b)?;
+    let scale = (0..n).map(|i| r[(i, i)].abs()).fold(0.0, f64::max);
+
+    let mut x = vec![0.0; n];
+    for i in (0..n).rev() {
+        if r[(i, i)].abs() <= scale * a.rows() as f64 * f64::EPSILON {
+            // columns of A are dependent, there's no unique minimizer
+            return Err(MathError::SingularMatrix);
+        }
+        let known: f64 = ((i + 1)..n).map(|j| r[(i, j)] * x[j]).sum();
+        x[i] = (rhs.0[i] - known) / r[(i, i)];
+    }
+
+    Ok(Vector(x))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{error::MathError, week5::vector::Vector};
+
+    use super::{solve_least_squares, Matrix};
+
+    fn m(rows: Vec<Vec<f64>>) -> Matrix {
+        Matrix::from_rows(rows.into_iter().map(Vector).collect()).unwrap()
+    }
+
+    #[test]
+    fn qr() {
+        let a = m(vec![
+            vec![12.0, -51.0, 4.0],
+            vec![6.0, 167.0, -68.0],
+            vec![-4.0, 24.0, -41.0],
+            vec![1.0, 1.0, 1.0],
+        ]);
+        let qr = a.qr();
+
+        assert_approx_eq!(&qr.q * &qr.r, a);
+        assert_approx_eq!(&qr.q.transposed() * &qr.q, Matrix::identity(4));
+        for i in 0..4 {
+            for j in 0..i.min(3) {
+                assert_eq!(qr.r[(i, j)], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn cholesky() {
+        let a = m(vec![
+            vec![4.0, 12.0, -16.0],
+            vec![12.0, 37.0, -43.0],
+            vec![-16.0, -43.0, 98.0],
+        ]);
+        let l = a.cholesky().unwrap();
+
+        assert_approx_eq!(
+            l,
+            m(vec![
+                vec![2.0, 0.0, 0.0],
+                vec![6.0, 1.0, 0.0],
+                vec![-8.0, 5.0, 3.0],
+            ])
+        );
+        assert_eq!(
+            m(vec![vec![1.0, 2.0], vec![2.0, 1.0]]).cholesky(),
+            Err(MathError::NotPositiveDefinite)
+        );
+    }
+
+    #[test]
+    fn svd() {
+        let a = m(vec![vec![3.0, 2.0, 2.0], vec![2.0, 3.0, -2.0]]);
+        let svd = a.svd().unwrap();
+
+        assert_approx_eq!(Vector(svd.singular_values.clone()), Vector(vec![5.0, 3.0]));
+        assert_approx_eq!(svd.reconstructed(), a);
+        assert_approx_eq!(&svd.u.transposed() * &svd.u, Matrix::identity(2));
+        assert_approx_eq!(&svd.v.transposed() * &svd.v, Matrix::identity(2));
+        assert_eq!(svd.rank(), 2);
+
+        let rank_one = m(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]);
+        assert_eq!(rank_one.svd().unwrap().rank(), 1);
+    }
+
+    #[test]
+    fn least_squares() {
+        // best fit line y = c0 + c1 x through (0, 6), (1, 0), (2, 0)
+        let a = m(vec![vec![1.0, 0.0], vec![1.0, 1.0], vec![1.0, 2.0]]);
+        let b = Vector(vec![6.0, 0.0, 0.0]);
+
+        assert_approx_eq!(
+            solve_least_squares(&a, &b).unwrap(),
+            Vector(vec![5.0, -3.0])
+        );
+        assert_eq!(
+            solve_least_squares(
+                &m(vec![vec![1.0, 2.0], vec![2.0, 4.0]]),
+                &Vector(vec![1.0, 1.0])
+            ),
+            Err(MathError::SingularMatrix)
+        );
+    }
+}
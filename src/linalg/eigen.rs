@@ -0,0 +1,598 @@
+use std::fmt::Display;
+
+use crate::approx::{ApproxEq, Tolerance};
+use crate::error::MathError;
+use crate::week5::{scalar::Scalar, vector::Vector};
+
+use super::matrix::Matrix;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub fn is_real(&self) -> bool {
+        self.im == 0.0
+    }
+}
+
+impl Display for Complex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.im < 0.0 {
+            write!(f, "{} - {}i", self.re, -self.im)
+        } else {
+            write!(f, "{} + {}i", self.re, self.im)
+        }
+    }
+}
+
+impl ApproxEq for Complex {
+    fn approx_eq_with(&self, other: &Complex, tolerance: Tolerance) -> bool {
+        self.re.approx_eq_with(&other.re, tolerance) && self.im.approx_eq_with(&other.im, tolerance)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Eigenpair {
+    pub value: f64,
+    // unit length
+    pub vector: Vector,
+    // |Av - λv|, how far the pair is from actually satisfying Av = λv
+    pub residual: Scalar,
+}
+
+impl Eigenpair {
+    fn new(matrix: &Matrix, value: f64, vector: Vector) -> Eigenpair {
+        let vector = vector.into_unit_in_direction();
+        let residual = (matrix * &vector - vector.multiplied(Scalar(value))).magnitude();
+        Eigenpair {
+            value,
+            vector,
+            residual,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen {
+    // sorted by eigenvalue, smallest first
+    pub pairs: Vec<Eigenpair>,
+}
+
+impl SymmetricEigen {
+    pub fn values(&self) -> Vec<f64> {
+        self.pairs.iter().map(|pair| pair.value).collect()
+    }
+
+    pub fn max_residual(&self) -> Scalar {
+        Scalar(
+            self.pairs
+                .iter()
+                .map(|pair| pair.residual.0)
+                .fold(0.0, f64::max),
+        )
+    }
+
+    // eigenvectors of a symmetric matrix should be mutually orthogonal
+    pub fn max_orthogonality_error(&self) -> Scalar {
+        Scalar(
+            self.pairs
+                .iter()
+                .enumerate()
+                .flat_map(|(i, a)| {
+                    self.pairs[i + 1..]
+                        .iter()
+                        .map(move |b| a.vector.dotted(&b.vector).0.abs())
+                })
+                .fold(0.0, f64::max),
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Eigen {
+    // every eigenvalue with its multiplicity, sorted by real then imaginary part
+    pub values: Vec<Complex>,
+    // an independent eigenvector for each real eigenvalue, as far as there are any. a defective
+    // matrix like [[1, 1], [0, 1]] has fewer eigenvectors than its eigenvalue's multiplicity, and
+    // only gets as many pairs as it has independent eigenvectors
+    pub real_pairs: Vec<Eigenpair>,
+}
+
+impl Eigen {
+    pub fn max_residual(&self) -> Scalar {
+        Scalar(
+            self.real_pairs
+                .iter()
+                .map(|pair| pair.residual.0)
+                .fold(0.0, f64::max),
+        )
+    }
+}
+
+const MAX_SWEEPS: usize = 100;
+const MAX_QR_ITERATIONS: usize = 60;
+
+impl Matrix {
+    pub fn is_symmetric(&self) -> bool {
+        self.approx_eq(&self.transposed())
+    }
+
+    // cyclic jacobi rotations, each one zeroing an off-diagonal pair
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen, MathError> {
+        self.require_square()?;
+        if !self.is_symmetric() {
+            return Err(MathError::NotSymmetric);
+        }
+
+        let size = self.rows();
+        let mut a = self.clone();
+        let mut v = Matrix::identity(size);
+        let scale: f64 = (0..size).flat_map(|i| a.row(i).iter().map(|x| x * x)).sum();
+
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let off_diagonal: f64 = (0..size)
+                .flat_map(|i| (0..size).filter(move |&j| j != i).map(move |j| (i, j)))
+                .map(|(i, j)| a[(i, j)] * a[(i, j)])
+                .sum();
+            if off_diagonal <= f64::EPSILON * f64::EPSILON * scale {
+                converged = true;
+                break;
+            }
+
+            for p in 0..size {
+                for q in (p + 1)..size {
+                    if a[(p, q)] == 0.0 {
+                        continue;
+                    }
+
+                    let theta = (a[(q, q)] - a[(p, p)]) / (2.0 * a[(p, q)]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    // A -> J^T A J
+                    for k in 0..size {
+                        let (akp, akq) = (a[(k, p)], a[(k, q)]);
+                        a[(k, p)] = c * akp - s * akq;
+                        a[(k, q)] = s * akp + c * akq;
+                    }
+                    for k in 0..size {
+                        let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+                        a[(p, k)] = c * apk - s * aqk;
+                        a[(q, k)] = s * apk + c * aqk;
+                    }
+                    // V -> V J
+                    for k in 0..size {
+                        let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                        v[(k, p)] = c * vkp - s * vkq;
+                        v[(k, q)] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+        if !converged {
+            return Err(MathError::NoConvergence);
+        }
+
+        let mut pairs: Vec<Eigenpair> = (0..size)
+            .map(|i| Eigenpair::new(self, a[(i, i)], v.column_vector(i)))
+            .collect();
+        pairs.sort_by(|a, b| a.value.total_cmp(&b.value));
+
+        Ok(SymmetricEigen { pairs })
+    }
+
+    // similar upper hessenberg matrix, by gaussian elimination with pivoting
+    pub fn hessenberg(&self) -> Result<Matrix, MathError> {
+        self.require_square()?;
+
+        let size = self.rows();
+        let mut a = self.clone();
+        for m in 1..size.saturating_sub(1) {
+            let pivot = (m..size)
+                .reduce(|best, i| {
+                    if a[(i, m - 1)].abs() > a[(best, m - 1)].abs() {
+                        i
+                    } else {
+                        best
+                    }
+                })
+                .unwrap();
+            let x = a[(pivot, m - 1)];
+            if pivot != m {
+                // similarity transform: swap both the rows and the columns
+                a.swap_rows(pivot, m);
+                for j in 0..size {
+                    let tmp = a[(j, pivot)];
+                    a[(j, pivot)] = a[(j, m)];
+                    a[(j, m)] = tmp;
+                }
+            }
+            if x == 0.0 {
+                continue;
+            }
+
+            for i in (m + 1)..size {
+                let y = a[(i, m - 1)] / x;
+                if y == 0.0 {
+                    continue;
+                }
+                a[(i, m - 1)] = 0.0;
+                for j in m..size {
+                    a[(i, j)] -= y * a[(m, j)];
+                }
+                for j in 0..size {
+                    a[(j, m)] += y * a[(j, i)];
+                }
+            }
+        }
+
+        Ok(a)
+    }
+
+    // francis double shift QR iterations on the hessenberg form
+    pub fn eigenvalues(&self) -> Result<Vec<Complex>, MathError> {
+        let mut a = self.hessenberg()?;
+        let size = a.rows();
+        let mut values = vec![Complex { re: 0.0, im: 0.0 }; size];
+
+        let norm: f64 = (0..size)
+            .flat_map(|i| (i.saturating_sub(1)..size).map(move |j| (i, j)))
+            .map(|(i, j)| a[(i, j)].abs())
+            .sum();
+
+        // shifts accumulated by exceptional shifts
+        let mut t = 0.0;
+        let mut nn = size as isize - 1;
+        while nn >= 0 {
+            let n = nn as usize;
+            let mut iterations = 0;
+            loop {
+                // look for a negligible subdiagonal entry to split the problem at
+                let mut l = n;
+                while l > 0 {
+                    let mut s = a[(l - 1, l - 1)].abs() + a[(l, l)].abs();
+                    if s == 0.0 {
+                        s = norm;
+                    }
+                    if a[(l, l - 1)].abs() <= f64::EPSILON * s {
+                        a[(l, l - 1)] = 0.0;
+                        break;
+                    }
+                    l -= 1;
+                }
+
+                let mut x = a[(n, n)];
+                if l == n {
+                    // one root found
+                    values[n] = Complex { re: x + t, im: 0.0 };
+                    nn -= 1;
+                    break;
+                }
+
+                let mut y = a[(n - 1, n - 1)];
+                let mut w = a[(n, n - 1)] * a[(n - 1, n)];
+                if l == n - 1 {
+                    // two roots found, from the trailing 2x2 block
+                    let p = 0.5 * (y - x);
+                    let q = p * p + w;
+                    let z = q.abs().sqrt();
+                    x += t;
+                    if q >= 0.0 {
+                        let z = p + z.copysign(p);
+                        values[n - 1] = Complex { re: x + z, im: 0.0 };
+                        values[n] = Complex {
+                            re: if z != 0.0 { x - w / z } else { x + z },
+                            im: 0.0,
+                        };
+                    } else {
+                        values[n - 1] = Complex { re: x + p, im: z };
+                        values[n] = Complex { re: x + p, im: -z };
+                    }
+                    nn -= 2;
+                    break;
+                }
+
+                if iterations == MAX_QR_ITERATIONS {
+                    return Err(MathError::NoConvergence);
+                }
+                if iterations == 10 || iterations == 20 {
+                    // exceptional shift
+                    t += x;
+                    for i in 0..=n {
+                        a[(i, i)] -= x;
+                    }
+                    let s = a[(n, n - 1)].abs() + a[(n - 1, n - 2)].abs();
+                    x = 0.75 * s;
+                    y = x;
+                    w = -0.4375 * s * s;
+                }
+                iterations += 1;
+
+                // look for two consecutive small subdiagonal entries
+                let (mut p, mut q, mut r);
+                let mut m = n - 2;
+                loop {
+                    let z = a[(m, m)];
+                    let rr = x - z;
+                    let ss = y - z;
+                    p = (rr * ss - w) / a[(m + 1, m)] + a[(m, m + 1)];
+                    q = a[(m + 1, m + 1)] - z - rr - ss;
+                    r = a[(m + 2, m + 1)];
+                    let s = p.abs() + q.abs() + r.abs();
+                    p /= s;
+                    q /= s;
+                    r /= s;
+                    if m == l {
+                        break;
+                    }
+                    let u = a[(m, m - 1)].abs() * (q.abs() + r.abs());
+                    let v = p.abs() * (a[(m - 1, m - 1)].abs() + z.abs() + a[(m + 1, m + 1)].abs());
+                    if u <= f64::EPSILON * v {
+                        break;
+                    }
+                    m -= 1;
+                }
+
+                for i in m..(n - 1) {
+                    a[(i + 2, i)] = 0.0;
+                    if i != m {
+                        a[(i + 2, i - 1)] = 0.0;
+                    }
+                }
+
+                // double QR step on rows l..=n and columns m..=n
+                for k in m..n {
+                    if k != m {
+                        p = a[(k, k - 1)];
+                        q = a[(k + 1, k - 1)];
+                        r = if k + 1 != n { a[(k + 2, k - 1)] } else { 0.0 };
+                        x = p.abs() + q.abs() + r.abs();
+                        if x != 0.0 {
+                            p /= x;
+                            q /= x;
+                            r /= x;
+                        }
+                    }
+                    let s = (p * p + q * q + r * r).sqrt().copysign(p);
+                    if s == 0.0 {
+                        continue;
+                    }
+
+                    if k == m {
+                        if l != m {
+                            a[(k, k - 1)] = -a[(k, k - 1)];
+                        }
+                    } else {
+                        a[(k, k - 1)] = -s * x;
+                    }
+                    p += s;
+                    x = p / s;
+                    y = q / s;
+                    let z = r / s;
+                    q /= p;
+                    r /= p;
+
+                    for j in k..=n {
+                        let mut p = a[(k, j)] + q * a[(k + 1, j)];
+                        if k + 1 != n {
+                            p += r * a[(k + 2, j)];
+                            a[(k + 2, j)] -= p * z;
+                        }
+                        a[(k + 1, j)] -= p * y;
+                        a[(k, j)] -= p * x;
+                    }
+                    for i in l..=n.min(k + 3) {
+                        let mut p = x * a[(i, k)] + y * a[(i, k + 1)];
+                        if k + 1 != n {
+                            p += z * a[(i, k + 2)];
+                            a[(i, k + 2)] -= p * r;
+                        }
+                        a[(i, k + 1)] -= p * q;
+                        a[(i, k)] -= p;
+                    }
+                }
+            }
+        }
+
+        values.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+        Ok(values)
+    }
+
+    pub fn eigen(&self) -> Result<Eigen, MathError> {
+        let values = self.eigenvalues()?;
+        let scale = (0..self.rows())
+            .flat_map(|i| self.row(i).iter().map(|x| x.abs()))
+            .fold(0.0, f64::max);
+        let mut real_pairs: Vec<Eigenpair> = vec![];
+        for value in values.iter().filter(|value| value.is_real()) {
+            // a repeated value needs a different vector every time it comes up
+            let repeated: Vec<Vector> = real_pairs
+                .iter()
+                .filter(|pair| pair.value.approx_eq(&value.re))
+                .map(|pair| pair.vector.clone())
+                .collect();
+            let vector = self.inverse_iteration(value.re, &repeated)?;
+            let pair = Eigenpair::new(self, value.re, vector);
+            // once the eigenspace has run out, the iteration can only come up with a
+            // generalized eigenvector, which isn't a solution of Av = λv
+            if pair.residual.0 <= scale * 1e-6 {
+                real_pairs.push(pair);
+            }
+        }
+
+        Ok(Eigen { values, real_pairs })
+    }
+
+    // the eigenvector for a known eigenvalue is the direction (A - λI)^-1 stretches the most.
+    // vectors already found for the same value are projected out on every step, so a repeated
+    // eigenvalue gets independent vectors as long as its eigenspace is big enough
+    fn inverse_iteration(&self, value: f64, found: &[Vector]) -> Result<Vector, MathError> {
+        let size = self.rows();
+        let scale = (0..size)
+            .flat_map(|i| self.row(i).iter().map(|x| x.abs()))
+            .fold(1.0, f64::max);
+        // nudge the shift so (A - λI) isn't exactly singular
+        let shift = value + scale * 1e-10;
+        let shifted = self.subtracted(&Matrix::identity(size).multiplied(Scalar(shift)))?;
+        let lu = shifted.lu()?;
+
+        // a fixed start can miss the eigenspace completely: (1, ..., 1) is itself an eigenvector
+        // when every row has the same sum, and for a symmetric matrix it then has nothing along
+        // the others. of a few starts, keep the one the first step stretches the most
+        let standard = (0..size).map(|i| {
+            let mut e = vec![0.0; size];
+            e[i] = 1.0;
+            Vector(e)
+        });
+        let mut best = (0.0, Vector(vec![1.0; size]));
+        for start in std::iter::once(Vector(vec![1.0; size])).chain(standard) {
+            let (_, start) = start.components_relative_to(found)?;
+            // already (almost) inside the span of what was found
+            if start.magnitude().0 < 1e-3 {
+                continue;
+            }
+            let (_, step) = lu.solve(&start)?.components_relative_to(found)?;
+            let stretch = step.magnitude().0 / start.magnitude().0;
+            if stretch > best.0 {
+                best = (stretch, step);
+            }
+        }
+
+        let mut v = best.1.into_unit_in_direction();
+        for _ in 0..2 {
+            let (_, step) = lu.solve(&v)?.components_relative_to(found)?;
+            v = step.into_unit_in_direction();
+        }
+        Ok(v)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{error::MathError, week5::vector::Vector};
+
+    use super::{Complex, Matrix};
+
+    fn m(rows: Vec<Vec<f64>>) -> Matrix {
+        Matrix::from_rows(rows.into_iter().map(Vector).collect()).unwrap()
+    }
+
+    fn real(re: f64) -> Complex {
+        Complex { re, im: 0.0 }
+    }
+
+    #[test]
+    fn symmetric() {
+        let a = m(vec![
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 2.0],
+        ]);
+        let eigen = a.symmetric_eigen().unwrap();
+        let sqrt2 = 2.0_f64.sqrt();
+
+        assert_approx_eq!(
+            Vector(eigen.values()),
+            Vector(vec![2.0 - sqrt2, 2.0, 2.0 + sqrt2])
+        );
+        assert!(eigen.max_residual().0 < 1e-10);
+        assert!(eigen.max_orthogonality_error().0 < 1e-10);
+
+        assert_eq!(
+            m(vec![vec![1.0, 2.0], vec![3.0, 4.0]])
+                .symmetric_eigen()
+                .err(),
+            Some(MathError::NotSymmetric)
+        );
+    }
+
+    #[test]
+    fn real_eigenvalues() {
+        let a = m(vec![vec![4.0, 1.0], vec![2.0, 3.0]]);
+        let eigen = a.eigen().unwrap();
+
+        assert_eq!(eigen.values.len(), 2);
+        assert_approx_eq!(eigen.values[0], real(2.0));
+        assert_approx_eq!(eigen.values[1], real(5.0));
+        assert!(eigen.max_residual().0 < 1e-8);
+    }
+
+    #[test]
+    fn repeated_eigenvalues() {
+        let eigen = m(vec![vec![2.0, 0.0], vec![0.0, 2.0]]).eigen().unwrap();
+        assert_eq!(eigen.real_pairs.len(), 2);
+        assert!(eigen.max_residual().0 < 1e-8);
+        let (u, v) = (&eigen.real_pairs[0].vector, &eigen.real_pairs[1].vector);
+        assert_approx_eq!(u.dotted(v).0, 0.0);
+
+        // a double eigenvalue with a plane of eigenvectors next to a single one
+        let a = m(vec![
+            vec![3.0, 1.0, 1.0],
+            vec![1.0, 3.0, 1.0],
+            vec![1.0, 1.0, 3.0],
+        ]);
+        let eigen = a.eigen().unwrap();
+        assert_approx_eq!(eigen.values[0], real(2.0));
+        assert_approx_eq!(eigen.values[1], real(2.0));
+        assert!(eigen.max_residual().0 < 1e-8);
+        let vectors: Vec<Vector> = eigen.real_pairs.iter().map(|p| p.vector.clone()).collect();
+        assert_eq!(Matrix::from_columns(vectors).unwrap().rank(), 3);
+    }
+
+    #[test]
+    fn defective() {
+        // 1 is a double eigenvalue, but every eigenvector is a multiple of (1, 0)
+        let eigen = m(vec![vec![1.0, 1.0], vec![0.0, 1.0]]).eigen().unwrap();
+        assert_eq!(eigen.values.len(), 2);
+        assert_eq!(eigen.real_pairs.len(), 1);
+        assert!(eigen.max_residual().0 < 1e-8);
+        assert_approx_eq!(eigen.real_pairs[0].vector.0[1], 0.0);
+    }
+
+    #[test]
+    fn complex_eigenvalues() {
+        // rotation by 90 degrees
+        let values = m(vec![vec![0.0, -1.0], vec![1.0, 0.0]])
+            .eigenvalues()
+            .unwrap();
+
+        assert_approx_eq!(values[0], Complex { re: 0.0, im: -1.0 });
+        assert_approx_eq!(values[1], Complex { re: 0.0, im: 1.0 });
+    }
+
+    #[test]
+    fn larger() {
+        let a = m(vec![
+            vec![1.0, 2.0, 0.0, 4.0, 1.0],
+            vec![3.0, -1.0, 2.0, 0.0, 5.0],
+            vec![0.0, 1.0, 4.0, -2.0, 1.0],
+            vec![2.0, 0.0, 1.0, 3.0, -1.0],
+            vec![1.0, 1.0, 1.0, 1.0, 1.0],
+        ]);
+        let eigen = a.eigen().unwrap();
+
+        let trace: f64 = eigen.values.iter().map(|v| v.re).sum();
+        let imaginary: f64 = eigen.values.iter().map(|v| v.im).sum();
+        assert_approx_eq!(trace, a.trace().unwrap().0);
+        assert_approx_eq!(imaginary, 0.0);
+        assert!(!eigen.real_pairs.is_empty());
+        assert!(eigen.max_residual().0 < 1e-8);
+
+        // triangular matrices have their diagonal as eigenvalues
+        let values = m(vec![
+            vec![3.0, 1.0, 4.0],
+            vec![0.0, -2.0, 5.0],
+            vec![0.0, 0.0, 7.0],
+        ])
+        .eigenvalues()
+        .unwrap();
+        assert_approx_eq!(values[0], real(-2.0));
+        assert_approx_eq!(values[1], real(3.0));
+        assert_approx_eq!(values[2], real(7.0));
+    }
+}
@@ -0,0 +1,417 @@
+use std::fmt::Display;
+
+use crate::approx::{ApproxEq, Tolerance};
+use crate::error::MathError;
+use crate::week5::vector::Vector;
+
+use super::matrix::Matrix;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RowOperation {
+    // R_i <-> R_j
+    Swap(usize, usize),
+    // R_i -> c R_i
+    Scale {
+        row: usize,
+        factor: f64,
+    },
+    // R_target -> R_target + c R_source
+    AddMultiple {
+        target: usize,
+        source: usize,
+        factor: f64,
+    },
+}
+
+impl RowOperation {
+    pub fn apply(&self, matrix: &mut Matrix) {
+        match *self {
+            RowOperation::Swap(i, j) => matrix.swap_rows(i, j),
+            RowOperation::Scale { row, factor } => {
+                matrix.row_mut(row).iter_mut().for_each(|x| *x *= factor)
+            }
+            RowOperation::AddMultiple {
+                target,
+                source,
+                factor,
+            } => {
+                let source = matrix.row(source).to_vec();
+                matrix
+                    .row_mut(target)
+                    .iter_mut()
+                    .zip(source)
+                    .for_each(|(x, s)| *x += factor * s);
+            }
+        }
+    }
+}
+
+impl Display for RowOperation {
+    // rows are numbered from 1, like in the textbook
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RowOperation::Swap(i, j) => write!(f, "R{} <-> R{}", i + 1, j + 1),
+            RowOperation::Scale { row, factor } => {
+                write!(f, "R{} -> ({})R{}", row + 1, factor, row + 1)
+            }
+            RowOperation::AddMultiple {
+                target,
+                source,
+                factor,
+            } => write!(
+                f,
+                "R{} -> R{} + ({})R{}",
+                target + 1,
+                target + 1,
+                factor,
+                source + 1
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RowReduction {
+    pub row_echelon: Matrix,
+    pub reduced_row_echelon: Matrix,
+    pub pivot_columns: Vec<usize>,
+    // every operation used, in order, going from the input to the reduced row echelon form.
+    // replaying them only matches the stored matrices up to rounding: after each operation the
+    // entry it was meant to clear (or make 1) is written exactly, and that isn't a step of its own
+    pub steps: Vec<RowOperation>,
+}
+
+impl RowReduction {
+    pub fn rank(&self) -> usize {
+        self.pivot_columns.len()
+    }
+
+    // solutions of the homogeneous system in the first `variables` columns, one per free
+    // variable: that variable set to 1, the other free ones to 0
+    pub fn null_space_basis(&self, variables: usize) -> Vec<Vector> {
+        let rref = &self.reduced_row_echelon;
+        let pivots: Vec<usize> = self
+            .pivot_columns
+            .iter()
+            .copied()
+            .filter(|&col| col < variables)
+            .collect();
+
+        (0..variables)
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                let mut v = vec![0.0; variables];
+                v[free] = 1.0;
+                for (row, &col) in pivots.iter().enumerate() {
+                    v[col] = -rref[(row, free)];
+                }
+                Vector(v)
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Solution {
+    Unique(Vector),
+    // every solution is point + t1 span[0] + t2 span[1] + ...
+    // point is a particular solution written as the coordinate vector (x1, ..., xn): systems can
+    // have any number of unknowns, and the point types only exist for a dimension fixed at
+    // compile time
+    Infinite { point: Vector, span: Vec<Vector> },
+    Inconsistent,
+}
+
+#[derive(Debug, Clone)]
+pub struct LinearSystem {
+    pub reduction: RowReduction,
+    pub pivot_columns: Vec<usize>,
+    pub free_variables: Vec<usize>,
+    pub rank: usize,
+    pub solution: Solution,
+}
+
+// entries this small (relative to the largest entry) are treated as zero while eliminating
+fn is_zero(x: f64, scale: f64) -> bool {
+    x.approx_eq_with(&0.0, Tolerance::absolute(scale * 1e-12))
+}
+
+impl Matrix {
+    pub fn row_reduce(&self) -> RowReduction {
+        let scale = (0..self.rows())
+            .flat_map(|i| self.row(i).iter().map(|x| x.abs()))
+            .fold(0.0, f64::max);
+        // nothing to pivot on, and no scale to measure "small" against
+        if scale == 0.0 {
+            return RowReduction {
+                row_echelon: self.clone(),
+                reduced_row_echelon: self.clone(),
+                pivot_columns: vec![],
+                steps: vec![],
+            };
+        }
+
+        let mut matrix = self.clone();
+        let mut steps = vec![];
+        let mut pivot_columns = vec![];
+
+        let mut apply = |matrix: &mut Matrix, operation: RowOperation| {
+            operation.apply(matrix);
+            steps.push(operation);
+        };
+
+        // forward elimination to row echelon form
+        for col in 0..self.cols() {
+            let row = pivot_columns.len();
+            if row == self.rows() {
+                break;
+            }
+
+            // the first row with the largest entry in this column
+            let pivot_row = (row..self.rows())
+                .reduce(|best, i| {
+                    if matrix[(i, col)].abs() > matrix[(best, col)].abs() {
+                        i
+                    } else {
+                        best
+                    }
+                })
+                .unwrap();
+            if is_zero(matrix[(pivot_row, col)], scale) {
+                continue;
+            }
+            if pivot_row != row {
+                apply(&mut matrix, RowOperation::Swap(row, pivot_row));
+            }
+
+            for below in (row + 1)..self.rows() {
+                let factor = -matrix[(below, col)] / matrix[(row, col)];
+                if factor != 0.0 {
+                    apply(
+                        &mut matrix,
+                        RowOperation::AddMultiple {
+                            target: below,
+                            source: row,
+                            factor,
+                        },
+                    );
+                }
+                // clear out rounding errors
+                matrix[(below, col)] = 0.0;
+            }
+
+            pivot_columns.push(col);
+        }
+
+        let row_echelon = matrix.clone();
+
+        // back substitution to reduced row echelon form
+        for (row, &col) in pivot_columns.iter().enumerate().rev() {
+            let pivot = matrix[(row, col)];
+            if pivot != 1.0 {
+                apply(
+                    &mut matrix,
+                    RowOperation::Scale {
+                        row,
+                        factor: 1.0 / pivot,
+                    },
+                );
+                matrix[(row, col)] = 1.0;
+            }
+
+            for above in 0..row {
+                let factor = -matrix[(above, col)];
+                if factor != 0.0 {
+                    apply(
+                        &mut matrix,
+                        RowOperation::AddMultiple {
+                            target: above,
+                            source: row,
+                            factor,
+                        },
+                    );
+                }
+                matrix[(above, col)] = 0.0;
+            }
+        }
+
+        RowReduction {
+            row_echelon,
+            reduced_row_echelon: matrix,
+            pivot_columns,
+            steps,
+        }
+    }
+}
+
+// solves the system whose augmented matrix is [A | b]
+pub fn solve_augmented(augmented: &Matrix) -> Result<LinearSystem, MathError> {
+    if augmented.cols() < 2 {
+        return Err(MathError::UnsupportedDimension {
+            expected: 2,
+            found: augmented.cols(),
+        });
+    }
+
+    let variables = augmented.cols() - 1;
+    let reduction = augmented.row_reduce();
+    let rref = &reduction.reduced_row_echelon;
+
+    let pivot_columns: Vec<usize> = reduction
+        .pivot_columns
+        .iter()
+        .copied()
+        .filter(|&col| col < variables)
+        .collect();
+    let free_variables: Vec<usize> = (0..variables)
+        .filter(|col| !pivot_columns.contains(col))
+        .collect();
+    let rank = pivot_columns.len();
+
+    // a pivot in the augmented column means a row reads 0 = 1
+    let solution = if reduction.pivot_columns.contains(&variables) {
+        Solution::Inconsistent
+    } else {
+        let mut point = vec![0.0; variables];
+        for (row, &col) in pivot_columns.iter().enumerate() {
+            point[col] = rref[(row, variables)];
+        }
+
+        if free_variables.is_empty() {
+            Solution::Unique(Vector(point))
+        } else {
+            Solution::Infinite {
+                point: Vector(point),
+                span: reduction.null_space_basis(variables),
+            }
+        }
+    };
+
+    Ok(LinearSystem {
+        reduction,
+        pivot_columns,
+        free_variables,
+        rank,
+        solution,
+    })
+}
+
+// solves Ax = b
+pub fn solve(a: &Matrix, b: &Vector) -> Result<LinearSystem, MathError> {
+    if a.rows() != b.0.len() {
+        return Err(MathError::DimensionMismatch {
+            left: a.rows(),
+            right: b.0.len(),
+        });
+    }
+    let mut columns = a.column_vectors();
+    columns.push(b.clone());
+    solve_augmented(&Matrix::from_columns(columns)?)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::week5::vector::Vector;
+
+    use super::{solve, solve_augmented, Matrix, RowOperation, Solution};
+
+    fn m(rows: Vec<Vec<f64>>) -> Matrix {
+        Matrix::from_rows(rows.into_iter().map(Vector).collect()).unwrap()
+    }
+
+    #[test]
+    fn unique() {
+        let system = solve_augmented(&m(vec![
+            vec![1.0, 1.0, 1.0, 6.0],
+            vec![0.0, 2.0, 5.0, -4.0],
+            vec![2.0, 5.0, -1.0, 27.0],
+        ]))
+        .unwrap();
+
+        assert_eq!(system.rank, 3);
+        assert!(system.free_variables.is_empty());
+        match system.solution {
+            Solution::Unique(x) => assert_approx_eq!(x, Vector(vec![5.0, 3.0, -2.0])),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn infinite() {
+        // x + 2y - z = 3, 2x + 4y - 2z = 6
+        let a = m(vec![vec![1.0, 2.0, -1.0], vec![2.0, 4.0, -2.0]]);
+        let b = Vector(vec![3.0, 6.0]);
+        let system = solve(&a, &b).unwrap();
+
+        assert_eq!(system.rank, 1);
+        assert_eq!(system.pivot_columns, vec![0]);
+        assert_eq!(system.free_variables, vec![1, 2]);
+        match system.solution {
+            Solution::Infinite { point, span } => {
+                assert_approx_eq!(&a * &point, b);
+                assert_eq!(span.len(), 2);
+                for direction in span {
+                    assert_approx_eq!(&a * &direction, Vector(vec![0.0, 0.0]));
+                }
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn small_entries() {
+        // tiny, but nowhere near singular: "small" is measured against the largest entry
+        let tiny = m(vec![vec![1e-13, 0.0], vec![0.0, 1e-13]]);
+        let reduction = tiny.row_reduce();
+        assert_eq!(reduction.rank(), 2);
+        assert_approx_eq!(reduction.reduced_row_echelon, Matrix::identity(2));
+        assert!(tiny.inverse().is_ok());
+
+        let zero = Matrix::zero(2, 3).row_reduce();
+        assert_eq!(zero.rank(), 0);
+        assert!(zero.steps.is_empty());
+    }
+
+    #[test]
+    fn inconsistent() {
+        let system = solve_augmented(&m(vec![vec![1.0, 1.0, 2.0], vec![1.0, 1.0, 3.0]])).unwrap();
+
+        assert_eq!(system.solution, Solution::Inconsistent);
+        assert_eq!(system.rank, 1);
+    }
+
+    #[test]
+    fn echelon_forms() {
+        let a = m(vec![
+            vec![0.0, 3.0, -6.0, 6.0, 4.0, -5.0],
+            vec![3.0, -7.0, 8.0, -5.0, 8.0, 9.0],
+            vec![3.0, -9.0, 12.0, -9.0, 6.0, 15.0],
+        ]);
+        let reduction = a.row_reduce();
+
+        assert_eq!(reduction.pivot_columns, vec![0, 1, 4]);
+        assert_approx_eq!(
+            reduction.reduced_row_echelon,
+            m(vec![
+                vec![1.0, 0.0, -2.0, 3.0, 0.0, -24.0],
+                vec![0.0, 1.0, -2.0, 2.0, 0.0, -7.0],
+                vec![0.0, 0.0, 0.0, 0.0, 1.0, 4.0],
+            ])
+        );
+        for row in 0..3 {
+            for col in 0..reduction.pivot_columns[row] {
+                assert_eq!(reduction.row_echelon[(row, col)], 0.0);
+            }
+        }
+
+        // replaying the trace reproduces the result
+        let mut replayed = a.clone();
+        reduction
+            .steps
+            .iter()
+            .for_each(|step| step.apply(&mut replayed));
+        assert_approx_eq!(replayed, reduction.reduced_row_echelon);
+        assert_eq!(reduction.steps[0], RowOperation::Swap(0, 1));
+        assert_eq!(reduction.steps[0].to_string(), "R1 <-> R2");
+    }
+}
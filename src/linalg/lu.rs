@@ -0,0 +1,231 @@
+use crate::error::MathError;
+use crate::week5::{scalar::Scalar, vector::Vector};
+
+use super::matrix::Matrix;
+
+// PA = LU, found with gaussian elimination and partial pivoting
+#[derive(Debug, Clone)]
+pub struct Lu {
+    // L strictly below the diagonal (its diagonal is all ones), U on and above it
+    factors: Matrix,
+    // row i of PA is row permutation[i] of A
+    permutation: Vec<usize>,
+    swaps: usize,
+}
+
+impl Matrix {
+    pub fn lu(&self) -> Result<Lu, MathError> {
+        self.require_square()?;
+
+        let size = self.rows();
+        let mut factors = self.clone();
+        let mut permutation: Vec<usize> = (0..size).collect();
+        let mut swaps = 0;
+
+        for k in 0..size {
+            // largest remaining entry in this column becomes the pivot
+            let pivot_row = (k..size)
+                .max_by(|&a, &b| factors[(a, k)].abs().total_cmp(&factors[(b, k)].abs()))
+                .unwrap();
+            if pivot_row != k {
+                factors.swap_rows(k, pivot_row);
+                permutation.swap(k, pivot_row);
+                swaps += 1;
+            }
+
+            let pivot = factors[(k, k)];
+            if pivot == 0.0 {
+                // nothing to eliminate, the matrix is singular
+                continue;
+            }
+
+            for i in (k + 1)..size {
+                let multiplier = factors[(i, k)] / pivot;
+                factors[(i, k)] = multiplier;
+                for j in (k + 1)..size {
+                    factors[(i, j)] -= multiplier * factors[(k, j)];
+                }
+            }
+        }
+
+        Ok(Lu {
+            factors,
+            permutation,
+            swaps,
+        })
+    }
+
+    pub fn determinant(&self) -> Result<Scalar, MathError> {
+        Ok(self.lu()?.determinant())
+    }
+}
+
+impl Lu {
+    pub fn size(&self) -> usize {
+        self.factors.rows()
+    }
+
+    pub fn lower(&self) -> Matrix {
+        Matrix::from_fn(self.size(), self.size(), |i, j| match i.cmp(&j) {
+            std::cmp::Ordering::Less => 0.0,
+            std::cmp::Ordering::Equal => 1.0,
+            std::cmp::Ordering::Greater => self.factors[(i, j)],
+        })
+    }
+
+    pub fn upper(&self) -> Matrix {
+        Matrix::from_fn(self.size(), self.size(), |i, j| {
+            if i <= j {
+                self.factors[(i, j)]
+            } else {
+                0.0
+            }
+        })
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn permutation_matrix(&self) -> Matrix {
+        Matrix::from_fn(self.size(), self.size(), |i, j| {
+            if self.permutation[i] == j {
+                1.0
+            } else {
+                0.0
+            }
+        })
+    }
+
+    pub fn determinant(&self) -> Scalar {
+        let sign = if self.swaps.is_multiple_of(2) {
+            1.0
+        } else {
+            -1.0
+        };
+        Scalar(
+            sign * (0..self.size())
+                .map(|i| self.factors[(i, i)])
+                .product::<f64>(),
+        )
+    }
+
+    pub fn is_singular(&self) -> bool {
+        let scale = (0..self.size())
+            .map(|i| self.factors[(i, i)].abs())
+            .fold(0.0, f64::max);
+        let threshold = scale * self.size() as f64 * f64::EPSILON;
+        (0..self.size()).any(|i| self.factors[(i, i)].abs() <= threshold)
+    }
+
+    // solves Ax = b
+    pub fn solve(&self, b: &Vector) -> Result<Vector, MathError> {
+        let size = self.size();
+        if b.0.len() != size {
+            return Err(MathError::DimensionMismatch {
+                left: size,
+                right: b.0.len(),
+            });
+        }
+        if self.is_singular() {
+            return Err(MathError::SingularMatrix);
+        }
+
+        // Ly = Pb
+        let mut x: Vec<f64> = self.permutation.iter().map(|&i| b.0[i]).collect();
+        for i in 0..size {
+            for j in 0..i {
+                x[i] -= self.factors[(i, j)] * x[j];
+            }
+        }
+        // Ux = y
+        for i in (0..size).rev() {
+            for j in (i + 1)..size {
+                x[i] -= self.factors[(i, j)] * x[j];
+            }
+            x[i] /= self.factors[(i, i)];
+        }
+
+        Ok(Vector(x))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::MathError,
+        week5::{scalar::Scalar, vector::Vector},
+    };
+
+    use super::Matrix;
+
+    fn m(rows: Vec<Vec<f64>>) -> Matrix {
+        Matrix::from_rows(rows.into_iter().map(Vector).collect()).unwrap()
+    }
+
+    #[test]
+    fn factors() {
+        let a = m(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![2.0, 5.0, 3.0],
+            vec![1.0, 0.0, 8.0],
+        ]);
+        let lu = a.lu().unwrap();
+
+        assert_approx_eq!(&lu.permutation_matrix() * &a, &lu.lower() * &lu.upper());
+        assert_approx_eq!(lu.determinant(), Scalar(-1.0));
+    }
+
+    #[test]
+    fn determinant() {
+        assert_approx_eq!(
+            m(vec![vec![3.0, 8.0], vec![4.0, 6.0]])
+                .determinant()
+                .unwrap(),
+            Scalar(-14.0)
+        );
+        assert_approx_eq!(
+            m(vec![
+                vec![6.0, 1.0, 1.0],
+                vec![4.0, -2.0, 5.0],
+                vec![2.0, 8.0, 7.0]
+            ])
+            .determinant()
+            .unwrap(),
+            Scalar(-306.0)
+        );
+        assert_approx_eq!(Matrix::identity(50).determinant().unwrap(), Scalar(1.0));
+        assert_eq!(
+            m(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).determinant(),
+            Ok(Scalar(0.0))
+        );
+        assert_eq!(
+            Matrix::zero(2, 3).determinant(),
+            Err(MathError::NonSquareMatrix { rows: 2, cols: 3 })
+        );
+    }
+
+    #[test]
+    fn solve() {
+        let a = m(vec![
+            vec![2.0, 1.0, -1.0],
+            vec![-3.0, -1.0, 2.0],
+            vec![-2.0, 1.0, 2.0],
+        ]);
+
+        assert_approx_eq!(
+            a.lu()
+                .unwrap()
+                .solve(&Vector(vec![8.0, -11.0, -3.0]))
+                .unwrap(),
+            Vector(vec![2.0, 3.0, -1.0])
+        );
+        assert_eq!(
+            m(vec![vec![1.0, 2.0], vec![2.0, 4.0]])
+                .lu()
+                .unwrap()
+                .solve(&Vector(vec![1.0, 1.0])),
+            Err(MathError::SingularMatrix)
+        );
+    }
+}
@@ -0,0 +1,410 @@
+use std::fmt::Display;
+use std::ops::*;
+
+use crate::approx::{ApproxEq, Tolerance};
+use crate::error::MathError;
+use crate::week5::{scalar::Scalar, vector::Vector};
+
+// entries are stored row by row
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    entries: Vec<f64>,
+}
+
+impl Matrix {
+    pub fn new(rows: usize, cols: usize, entries: Vec<f64>) -> Result<Matrix, MathError> {
+        if entries.len() != rows * cols {
+            return Err(MathError::DimensionMismatch {
+                left: rows * cols,
+                right: entries.len(),
+            });
+        }
+        Ok(Matrix {
+            rows,
+            cols,
+            entries,
+        })
+    }
+
+    pub fn from_fn(rows: usize, cols: usize, f: impl Fn(usize, usize) -> f64) -> Matrix {
+        Matrix {
+            rows,
+            cols,
+            entries: (0..rows * cols).map(|i| f(i / cols, i % cols)).collect(),
+        }
+    }
+
+    pub fn zero(rows: usize, cols: usize) -> Matrix {
+        Matrix::from_fn(rows, cols, |_, _| 0.0)
+    }
+
+    pub fn identity(size: usize) -> Matrix {
+        Matrix::from_fn(size, size, |i, j| if i == j { 1.0 } else { 0.0 })
+    }
+
+    pub fn from_rows(rows: Vec<Vector>) -> Result<Matrix, MathError> {
+        let cols = rows.first().map_or(0, |row| row.0.len());
+        if let Some(row) = rows.iter().find(|row| row.0.len() != cols) {
+            return Err(MathError::DimensionMismatch {
+                left: cols,
+                right: row.0.len(),
+            });
+        }
+        Matrix::new(
+            rows.len(),
+            cols,
+            rows.into_iter().flat_map(|v| v.0).collect(),
+        )
+    }
+
+    pub fn from_columns(columns: Vec<Vector>) -> Result<Matrix, MathError> {
+        Ok(Matrix::from_rows(columns)?.transposed())
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn row(&self, i: usize) -> &[f64] {
+        &self.entries[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> &mut [f64] {
+        &mut self.entries[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn column(&self, j: usize) -> impl Iterator<Item = f64> + '_ {
+        // skip(j) would quietly wrap around into the next row otherwise
+        assert!(
+            j < self.cols,
+            "column {} out of range for {} columns",
+            j,
+            self.cols
+        );
+        self.entries.iter().skip(j).step_by(self.cols).copied()
+    }
+
+    pub fn row_vector(&self, i: usize) -> Vector {
+        Vector(self.row(i).to_vec())
+    }
+
+    pub fn column_vector(&self, j: usize) -> Vector {
+        Vector(self.column(j).collect())
+    }
+
+    pub fn row_vectors(&self) -> Vec<Vector> {
+        (0..self.rows).map(|i| self.row_vector(i)).collect()
+    }
+
+    pub fn column_vectors(&self) -> Vec<Vector> {
+        (0..self.cols).map(|j| self.column_vector(j)).collect()
+    }
+
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        for col in 0..self.cols {
+            self.entries.swap(i * self.cols + col, j * self.cols + col);
+        }
+    }
+
+    pub fn transposed(&self) -> Matrix {
+        Matrix::from_fn(self.cols, self.rows, |i, j| self[(j, i)])
+    }
+
+    pub fn trace(&self) -> Result<Scalar, MathError> {
+        self.require_square()?;
+        Ok(Scalar((0..self.rows).map(|i| self[(i, i)]).sum()))
+    }
+
+    pub fn added(&self, other: &Matrix) -> Result<Matrix, MathError> {
+        self.require_same_shape(other)?;
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            entries: self
+                .entries
+                .iter()
+                .zip(other.entries.iter())
+                .map(|(x1, x2)| x1 + x2)
+                .collect(),
+        })
+    }
+
+    pub fn subtracted(&self, other: &Matrix) -> Result<Matrix, MathError> {
+        self.added(&other.multiplied(Scalar(-1.0)))
+    }
+
+    pub fn checked_add(self, rhs: Matrix) -> Result<Matrix, MathError> {
+        self.added(&rhs)
+    }
+
+    pub fn checked_sub(self, rhs: Matrix) -> Result<Matrix, MathError> {
+        self.subtracted(&rhs)
+    }
+
+    pub fn multiplied(&self, scalar: Scalar) -> Matrix {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            entries: self.entries.iter().map(|x| x * scalar.0).collect(),
+        }
+    }
+
+    // matrix - matrix product
+    pub fn product(&self, other: &Matrix) -> Result<Matrix, MathError> {
+        if self.cols != other.rows {
+            return Err(MathError::DimensionMismatch {
+                left: self.cols,
+                right: other.rows,
+            });
+        }
+        Ok(Matrix::from_fn(self.rows, other.cols, |i, j| {
+            self.row(i)
+                .iter()
+                .zip(other.column(j))
+                .map(|(x1, x2)| x1 * x2)
+                .sum()
+        }))
+    }
+
+    // matrix - vector product, treating the vector as a column
+    pub fn apply(&self, vector: &Vector) -> Result<Vector, MathError> {
+        if self.cols != vector.0.len() {
+            return Err(MathError::DimensionMismatch {
+                left: self.cols,
+                right: vector.0.len(),
+            });
+        }
+        Ok(Vector(
+            (0..self.rows)
+                .map(|i| Vector(self.row(i).to_vec()).dotted(vector).0)
+                .collect(),
+        ))
+    }
+
+    pub(crate) fn require_square(&self) -> Result<(), MathError> {
+        if self.is_square() {
+            Ok(())
+        } else {
+            Err(MathError::NonSquareMatrix {
+                rows: self.rows,
+                cols: self.cols,
+            })
+        }
+    }
+
+    fn require_same_shape(&self, other: &Matrix) -> Result<(), MathError> {
+        if self.rows != other.rows {
+            Err(MathError::DimensionMismatch {
+                left: self.rows,
+                right: other.rows,
+            })
+        } else if self.cols != other.cols {
+            Err(MathError::DimensionMismatch {
+                left: self.cols,
+                right: other.cols,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.rows && col < self.cols);
+        &self.entries[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(row < self.rows && col < self.cols);
+        &mut self.entries[row * self.cols + col]
+    }
+}
+
+impl ApproxEq for Matrix {
+    fn approx_eq_with(&self, other: &Matrix, tolerance: Tolerance) -> bool {
+        self.rows == other.rows
+            && self.cols == other.cols
+            && self.entries[..].approx_eq_with(&other.entries[..], tolerance)
+    }
+}
+
+impl Display for Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for i in 0..self.rows {
+            let row: Vec<String> = self.row(i).iter().map(|x| x.to_string()).collect();
+            writeln!(f, "[{}]", row.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+// ========== matrix - matrix operations ==========
+
+impl Add for Matrix {
+    type Output = Matrix;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).unwrap()
+    }
+}
+
+impl Sub for Matrix {
+    type Output = Matrix;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).unwrap()
+    }
+}
+
+impl Mul<Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: Matrix) -> Self::Output {
+        self.product(&rhs).unwrap()
+    }
+}
+
+impl Mul<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: &Matrix) -> Self::Output {
+        self.product(rhs).unwrap()
+    }
+}
+
+// ========== matrix - vector operations ==========
+
+impl Mul<Vector> for Matrix {
+    type Output = Vector;
+
+    fn mul(self, rhs: Vector) -> Self::Output {
+        self.apply(&rhs).unwrap()
+    }
+}
+
+impl Mul<&Vector> for &Matrix {
+    type Output = Vector;
+
+    fn mul(self, rhs: &Vector) -> Self::Output {
+        self.apply(rhs).unwrap()
+    }
+}
+
+// ========== matrix - scalar operations ==========
+
+impl Mul<Scalar> for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: Scalar) -> Self::Output {
+        self.multiplied(rhs)
+    }
+}
+
+impl Mul<f64> for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.multiplied(Scalar(rhs))
+    }
+}
+
+impl Neg for Matrix {
+    type Output = Matrix;
+
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::MathError,
+        week5::{scalar::Scalar, vector::Vector},
+    };
+
+    use super::Matrix;
+
+    fn m(rows: Vec<Vec<f64>>) -> Matrix {
+        Matrix::from_rows(rows.into_iter().map(Vector).collect()).unwrap()
+    }
+
+    #[test]
+    fn construction() {
+        let rows = m(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        let columns = Matrix::from_columns(vec![
+            Vector(vec![1.0, 4.0]),
+            Vector(vec![2.0, 5.0]),
+            Vector(vec![3.0, 6.0]),
+        ])
+        .unwrap();
+
+        assert_eq!(rows, columns);
+        assert_eq!((rows.rows(), rows.cols()), (2, 3));
+        assert_eq!(rows.row(1), &[4.0, 5.0, 6.0]);
+        assert_eq!(rows.column_vector(2), Vector(vec![3.0, 6.0]));
+        assert_eq!(
+            Matrix::from_rows(vec![Vector(vec![1.0, 2.0]), Vector(vec![3.0])]),
+            Err(MathError::DimensionMismatch { left: 2, right: 1 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "column 2 out of range")]
+    fn column_out_of_range() {
+        m(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).column_vector(2);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = m(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        let b = m(vec![vec![0.0, 1.0], vec![1.0, 0.0]]);
+
+        assert_eq!(
+            a.clone() + b.clone(),
+            m(vec![vec![1.0, 3.0], vec![4.0, 4.0]])
+        );
+        assert_eq!(a.clone() - a.clone(), Matrix::zero(2, 2));
+        assert_eq!(a.clone() * 2.0, m(vec![vec![2.0, 4.0], vec![6.0, 8.0]]));
+        assert_eq!(&a * &b, m(vec![vec![2.0, 1.0], vec![4.0, 3.0]]));
+        assert_eq!(&a * &Matrix::identity(2), a);
+        assert_eq!(
+            a.clone() * Vector(vec![1.0, -1.0]),
+            Vector(vec![-1.0, -1.0])
+        );
+        assert_eq!(
+            a.product(&Matrix::identity(3)),
+            Err(MathError::DimensionMismatch { left: 2, right: 3 })
+        );
+    }
+
+    #[test]
+    fn transpose_and_trace() {
+        let a = m(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+
+        assert_eq!(
+            a.transposed(),
+            m(vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]])
+        );
+        assert_eq!(
+            a.trace(),
+            Err(MathError::NonSquareMatrix { rows: 2, cols: 3 })
+        );
+        assert_eq!((&a * &a.transposed()).trace(), Ok(Scalar(91.0)));
+    }
+}
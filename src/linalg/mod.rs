@@ -0,0 +1,10 @@
+pub mod affine;
+pub mod decompose;
+pub mod eigen;
+pub mod elimination;
+pub mod lu;
+pub mod matrix;
+pub mod orthogonal;
+pub mod quaternion;
+pub mod rotation;
+pub mod spaces;
@@ -0,0 +1,281 @@
+use crate::{
+    approx::{ApproxEq, Tolerance},
+    error::MathError,
+    week5::vector::Vector,
+    week7::{
+        plane::Plane3,
+        point::{Point2, Point3},
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct GramSchmidt {
+    // orthonormal, one for each input that wasn't dependent
+    pub basis: Vec<Vector>,
+    // indices of the inputs that were combinations of the ones before them, these are skipped
+    pub dependent: Vec<usize>,
+}
+
+// the textbook version: every input has the projections of the *original* vector removed, which
+// loses orthogonality quickly when the inputs are close to dependent
+pub fn gram_schmidt(vectors: &[Vector]) -> Result<GramSchmidt, MathError> {
+    gram_schmidt_with(vectors, Tolerance::DEFAULT)
+}
+
+pub fn gram_schmidt_with(
+    vectors: &[Vector],
+    tolerance: Tolerance,
+) -> Result<GramSchmidt, MathError> {
+    orthonormalize(vectors, tolerance, false)
+}
+
+// removes the projections one at a time from what is left so far, so rounding errors made along
+// the way are projected out too
+pub fn modified_gram_schmidt(vectors: &[Vector]) -> Result<GramSchmidt, MathError> {
+    modified_gram_schmidt_with(vectors, Tolerance::DEFAULT)
+}
+
+pub fn modified_gram_schmidt_with(
+    vectors: &[Vector],
+    tolerance: Tolerance,
+) -> Result<GramSchmidt, MathError> {
+    orthonormalize(vectors, tolerance, true)
+}
+
+// an input counts as dependent when what's left of it is tiny compared to its original length
+fn orthonormalize(
+    vectors: &[Vector],
+    tolerance: Tolerance,
+    modified: bool,
+) -> Result<GramSchmidt, MathError> {
+    let dimension = vectors.first().map_or(0, |v| v.0.len());
+    if let Some(v) = vectors.iter().find(|v| v.0.len() != dimension) {
+        return Err(MathError::DimensionMismatch {
+            left: dimension,
+            right: v.0.len(),
+        });
+    }
+
+    let mut result = GramSchmidt {
+        basis: vec![],
+        dependent: vec![],
+    };
+    for (i, v) in vectors.iter().enumerate() {
+        let mut remainder = v.clone();
+        for e in &result.basis {
+            let projection = if modified {
+                remainder.projected_on(e)
+            } else {
+                v.projected_on(e)
+            };
+            remainder = remainder - projection;
+        }
+
+        let length = v.magnitude().0;
+        if length == 0.0 || (remainder.magnitude().0 / length).approx_eq_with(&0.0, tolerance) {
+            result.dependent.push(i);
+        } else {
+            result.basis.push(remainder.into_unit_in_direction());
+        }
+    }
+    Ok(result)
+}
+
+// orthonormal basis for every vector perpendicular to all of the given ones. there's no way to
+// tell the dimension from an empty list, so that gives an empty basis
+pub fn orthogonal_complement(vectors: &[Vector]) -> Result<Vec<Vector>, MathError> {
+    let Some(first) = vectors.first() else {
+        return Ok(vec![]);
+    };
+    let dimension = first.0.len();
+
+    // the standard basis vectors that survive after the inputs span the rest of the space
+    let spanned = modified_gram_schmidt(vectors)?.basis.len();
+    let standard = (0..dimension).map(|i| {
+        let mut e = vec![0.0; dimension];
+        e[i] = 1.0;
+        Vector(e)
+    });
+    let all: Vec<Vector> = vectors.iter().cloned().chain(standard).collect();
+    Ok(modified_gram_schmidt(&all)?.basis.split_off(spanned))
+}
+
+impl Vector {
+    // (parallel, perpendicular), with parallel in the span of the subspace and the two adding up
+    // to self
+    pub fn components_relative_to(
+        &self,
+        subspace: &[Vector],
+    ) -> Result<(Vector, Vector), MathError> {
+        let basis = modified_gram_schmidt(subspace)?.basis;
+        let mut parallel = Vector(vec![0.0; self.0.len()]);
+        for e in &basis {
+            parallel = parallel.checked_add(self.checked_projected_on(e)?)?;
+        }
+        let perpendicular = self.subtracted(&parallel)?;
+        Ok((parallel, perpendicular))
+    }
+
+    // starts with the unit vector in this direction, 3d bases are right handed
+    pub fn orthonormal_basis_containing(&self) -> Result<Vec<Vector>, MathError> {
+        if self.magnitude().0 == 0.0 {
+            return Err(MathError::ZeroVector);
+        }
+        let mut basis = vec![self.unit_in_direction()];
+        basis.extend(orthogonal_complement(std::slice::from_ref(self))?);
+        if basis.len() == 3 {
+            basis[2] = basis[0].crossed(&basis[1])?;
+        }
+        Ok(basis)
+    }
+}
+
+impl Plane3 {
+    // [u, v, normal], orthonormal with u x v = normal
+    pub fn frame(&self) -> [Vector; 3] {
+        let mut basis = self.normal.orthonormal_basis_containing().unwrap();
+        let v = basis.pop().unwrap();
+        let u = basis.pop().unwrap();
+        [u, v, basis.pop().unwrap()]
+    }
+
+    // coordinates along u and v of the frame, measured from the plane's point. points off the
+    // plane are projected onto it first
+    pub fn to_local(&self, point: &Point3) -> Point2 {
+        let [u, v, _] = self.frame();
+        let offset = *point - self.point;
+        Point2(offset.dotted(&u).0, offset.dotted(&v).0)
+    }
+
+    pub fn from_local(&self, point: &Point2) -> Point3 {
+        let [u, v, _] = self.frame();
+        self.point + (u * point.0 + v * point.1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        approx::Tolerance,
+        error::MathError,
+        week5::vector::Vector,
+        week7::{
+            plane::Plane3,
+            point::{Point2, Point3},
+        },
+    };
+
+    use super::{gram_schmidt, modified_gram_schmidt, orthogonal_complement};
+
+    fn assert_orthonormal(basis: &[Vector]) {
+        for (i, u) in basis.iter().enumerate() {
+            for (j, v) in basis.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_approx_eq!(u.dotted(v).0, expected, Tolerance::absolute(1e-12));
+            }
+        }
+    }
+
+    #[test]
+    fn orthonormalization() {
+        let vectors = [
+            Vector(vec![1.0, 1.0, 0.0]),
+            Vector(vec![2.0, 2.0, 0.0]),
+            Vector(vec![1.0, 0.0, 1.0]),
+            Vector(vec![0.0, 1.0, -1.0]),
+        ];
+
+        for result in [gram_schmidt(&vectors), modified_gram_schmidt(&vectors)] {
+            let result = result.unwrap();
+            assert_eq!(result.dependent, vec![1, 3]);
+            assert_eq!(result.basis.len(), 2);
+            assert_orthonormal(&result.basis);
+            let root = 0.5_f64.sqrt();
+            assert_approx_eq!(result.basis[0], Vector(vec![root, root, 0.0]));
+        }
+
+        assert!(matches!(
+            gram_schmidt(&[Vector(vec![1.0]), Vector(vec![1.0, 2.0])]),
+            Err(MathError::DimensionMismatch { left: 1, right: 2 })
+        ));
+        assert_eq!(
+            gram_schmidt(&[Vector(vec![0.0, 0.0])]).unwrap().dependent,
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn modified_is_more_stable() {
+        // lauchli: nearly parallel columns, where classical gram schmidt falls apart
+        let e = 1e-8;
+        let vectors = [
+            Vector(vec![1.0, e, 0.0, 0.0]),
+            Vector(vec![1.0, 0.0, e, 0.0]),
+            Vector(vec![1.0, 0.0, 0.0, e]),
+        ];
+
+        let classical = gram_schmidt(&vectors).unwrap().basis;
+        let modified = modified_gram_schmidt(&vectors).unwrap().basis;
+        assert!(classical[1].dotted(&classical[2]).0.abs() > 0.1);
+        assert!(modified[1].dotted(&modified[2]).0.abs() < 1e-6);
+    }
+
+    #[test]
+    fn complements() {
+        let plane = orthogonal_complement(&[Vector(vec![1.0, 1.0, 1.0])]).unwrap();
+        assert_eq!(plane.len(), 2);
+        assert_orthonormal(&plane);
+        for v in &plane {
+            assert_approx_eq!(v.dotted(&Vector(vec![1.0, 1.0, 1.0])).0, 0.0);
+        }
+
+        let line = orthogonal_complement(&[
+            Vector(vec![1.0, 0.0, 0.0]),
+            Vector(vec![1.0, 1.0, 0.0]),
+            Vector(vec![3.0, 2.0, 0.0]),
+        ])
+        .unwrap();
+        assert_eq!(line.len(), 1);
+        assert_approx_eq!(line[0], Vector(vec![0.0, 0.0, 1.0]));
+
+        assert!(orthogonal_complement(&[Vector(vec![1.0, 2.0])])
+            .unwrap()
+            .iter()
+            .all(|v| v.dotted(&Vector(vec![1.0, 2.0])).0.abs() < 1e-12));
+    }
+
+    #[test]
+    fn components() {
+        let v = Vector(vec![1.0, 2.0, 3.0]);
+        let xy = [Vector(vec![1.0, 1.0, 0.0]), Vector(vec![1.0, -1.0, 0.0])];
+
+        let (parallel, perpendicular) = v.components_relative_to(&xy).unwrap();
+        assert_approx_eq!(parallel, Vector(vec![1.0, 2.0, 0.0]));
+        assert_approx_eq!(perpendicular, Vector(vec![0.0, 0.0, 3.0]));
+    }
+
+    #[test]
+    fn bases_and_frames() {
+        let n = Vector(vec![0.0, 3.0, 4.0]);
+        let basis = n.orthonormal_basis_containing().unwrap();
+        assert_eq!(basis.len(), 3);
+        assert_orthonormal(&basis);
+        assert_approx_eq!(basis[0], Vector(vec![0.0, 0.6, 0.8]));
+        assert_approx_eq!(basis[0].crossed(&basis[1]).unwrap(), basis[2]);
+        assert_eq!(
+            Vector(vec![0.0, 0.0]).orthonormal_basis_containing(),
+            Err(MathError::ZeroVector)
+        );
+
+        let plane = Plane3::from_point_and_normal(Point3(1.0, 2.0, 3.0), n);
+        let [u, v, normal] = plane.frame();
+        assert_approx_eq!(u.crossed(&v).unwrap(), normal);
+        assert_approx_eq!(normal, plane.normal);
+
+        let local = Point2(2.0, -1.5);
+        let point = plane.from_local(&local);
+        assert!(plane.contains(&point));
+        assert_approx_eq!(plane.to_local(&point), local);
+        assert_approx_eq!(plane.to_local(&plane.point), Point2(0.0, 0.0));
+    }
+}
@@ -0,0 +1,388 @@
+use std::fmt::Display;
+use std::ops::{Add, Mul, Neg};
+
+use crate::{
+    approx::{ApproxEq, Tolerance},
+    error::MathError,
+    week5::{
+        scalar::Scalar,
+        vector::{unit::three_d, Vector},
+    },
+    week6::angle::{Angle, Radians},
+};
+
+use super::matrix::Matrix;
+
+// w + xi + yj + zk. only unit quaternions describe rotations, and q and -q describe the same one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Quaternion {
+    pub const IDENTITY: Quaternion = Quaternion::new(1.0, 0.0, 0.0, 0.0);
+
+    pub const fn new(w: f64, x: f64, y: f64, z: f64) -> Quaternion {
+        Quaternion { w, x, y, z }
+    }
+
+    // the (x, y, z) part
+    pub fn vector_part(&self) -> Vector {
+        Vector(vec![self.x, self.y, self.z])
+    }
+
+    pub fn dot(&self, other: &Quaternion) -> f64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    pub fn conjugate(&self) -> Quaternion {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    pub fn normalized(&self) -> Result<Quaternion, MathError> {
+        let norm = self.norm();
+        if norm == 0.0 {
+            return Err(MathError::ZeroVector);
+        }
+        Ok(*self * (1.0 / norm))
+    }
+
+    pub fn inverse(&self) -> Result<Quaternion, MathError> {
+        let norm_squared = self.dot(self);
+        if norm_squared == 0.0 {
+            return Err(MathError::ZeroVector);
+        }
+        Ok(self.conjugate() * (1.0 / norm_squared))
+    }
+
+    pub fn from_axis_angle(axis: &Vector, angle: impl Angle) -> Result<Quaternion, MathError> {
+        if axis.0.len() != 3 {
+            return Err(MathError::UnsupportedDimension {
+                expected: 3,
+                found: axis.0.len(),
+            });
+        }
+        if axis.magnitude().0 == 0.0 {
+            return Err(MathError::ZeroVector);
+        }
+        let half: Radians = Into::<Radians>::into(angle) / 2.0;
+        let v = axis.unit_in_direction().multiplied(Scalar(half.sin()));
+        Ok(Quaternion::new(half.cos(), v.0[0], v.0[1], v.0[2]))
+    }
+
+    // the angle is in [0, 2pi], the identity rotation gets i as its axis
+    pub fn to_axis_angle(&self) -> (Vector, Radians) {
+        let v = self.vector_part();
+        let sin = v.magnitude().0;
+        if sin == 0.0 {
+            return (three_d::i(), Radians(0.0));
+        }
+        (v.into_unit_in_direction(), Radians(2.0 * sin.atan2(self.w)))
+    }
+
+    // non unit quaternions are normalized first, so only zero is rejected
+    pub fn to_rotation_matrix(&self) -> Result<Matrix, MathError> {
+        let Quaternion { w, x, y, z } = *self;
+        let norm_squared = self.dot(self);
+        if norm_squared == 0.0 {
+            return Err(MathError::ZeroVector);
+        }
+        let s = 2.0 / norm_squared;
+        Matrix::new(
+            3,
+            3,
+            vec![
+                1.0 - s * (y * y + z * z),
+                s * (x * y - w * z),
+                s * (x * z + w * y),
+                s * (x * y + w * z),
+                1.0 - s * (x * x + z * z),
+                s * (y * z - w * x),
+                s * (x * z - w * y),
+                s * (y * z + w * x),
+                1.0 - s * (x * x + y * y),
+            ],
+        )
+    }
+
+    // the matrix is assumed to be a rotation: orthogonal with determinant 1
+    pub fn from_rotation_matrix(m: &Matrix) -> Result<Quaternion, MathError> {
+        m.require_square()?;
+        if m.rows() != 3 {
+            return Err(MathError::UnsupportedDimension {
+                expected: 3,
+                found: m.rows(),
+            });
+        }
+
+        // divide by the largest of the four candidates, so nothing blows up
+        let trace = m[(0, 0)] + m[(1, 1)] + m[(2, 2)];
+        let q = if trace > 0.0 {
+            let s = 2.0 * (trace + 1.0).sqrt();
+            Quaternion::new(
+                s / 4.0,
+                (m[(2, 1)] - m[(1, 2)]) / s,
+                (m[(0, 2)] - m[(2, 0)]) / s,
+                (m[(1, 0)] - m[(0, 1)]) / s,
+            )
+        } else if m[(0, 0)] > m[(1, 1)] && m[(0, 0)] > m[(2, 2)] {
+            let s = 2.0 * (1.0 + m[(0, 0)] - m[(1, 1)] - m[(2, 2)]).sqrt();
+            Quaternion::new(
+                (m[(2, 1)] - m[(1, 2)]) / s,
+                s / 4.0,
+                (m[(0, 1)] + m[(1, 0)]) / s,
+                (m[(0, 2)] + m[(2, 0)]) / s,
+            )
+        } else if m[(1, 1)] > m[(2, 2)] {
+            let s = 2.0 * (1.0 + m[(1, 1)] - m[(0, 0)] - m[(2, 2)]).sqrt();
+            Quaternion::new(
+                (m[(0, 2)] - m[(2, 0)]) / s,
+                (m[(0, 1)] + m[(1, 0)]) / s,
+                s / 4.0,
+                (m[(1, 2)] + m[(2, 1)]) / s,
+            )
+        } else {
+            let s = 2.0 * (1.0 + m[(2, 2)] - m[(0, 0)] - m[(1, 1)]).sqrt();
+            Quaternion::new(
+                (m[(1, 0)] - m[(0, 1)]) / s,
+                (m[(0, 2)] + m[(2, 0)]) / s,
+                (m[(1, 2)] + m[(2, 1)]) / s,
+                s / 4.0,
+            )
+        };
+        Ok(q)
+    }
+
+    // q v q*, with q normalized first
+    pub fn rotate(&self, v: &Vector) -> Result<Vector, MathError> {
+        if v.0.len() != 3 {
+            return Err(MathError::UnsupportedDimension {
+                expected: 3,
+                found: v.0.len(),
+            });
+        }
+        let q = self.normalized()?;
+        let rotated = q * Quaternion::new(0.0, v.0[0], v.0[1], v.0[2]) * q.conjugate();
+        Ok(rotated.vector_part())
+    }
+
+    // spherical linear interpolation between two rotations, t = 0 gives self and t = 1 other.
+    // always takes the short way around
+    pub fn slerp(&self, other: &Quaternion, t: f64) -> Result<Quaternion, MathError> {
+        let from = self.normalized()?;
+        let mut to = other.normalized()?;
+        let mut cos = from.dot(&to);
+        if cos < 0.0 {
+            to = -to;
+            cos = -cos;
+        }
+        // nearly the same rotation, sin(theta) would be too small to divide by
+        if cos > 1.0 - 1e-6 {
+            return (from * (1.0 - t) + to * t).normalized();
+        }
+        let theta = cos.acos();
+        Ok((from * ((1.0 - t) * theta).sin() + to * (t * theta).sin()) * (1.0 / theta.sin()))
+    }
+}
+
+// hamilton product: p * q is the rotation q followed by p
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Self::Output {
+        let (a, b) = (self, rhs);
+        Quaternion::new(
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        )
+    }
+}
+
+impl Mul<f64> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Quaternion::new(self.w * rhs, self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Add for Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, rhs: Quaternion) -> Self::Output {
+        Quaternion::new(
+            self.w + rhs.w,
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+        )
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
+impl Display for Quaternion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} + {}i + {}j + {}k", self.w, self.x, self.y, self.z)
+    }
+}
+
+impl ApproxEq for Quaternion {
+    fn approx_eq_with(&self, other: &Quaternion, tolerance: Tolerance) -> bool {
+        [self.w, self.x, self.y, self.z][..]
+            .approx_eq_with(&[other.w, other.x, other.y, other.z][..], tolerance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use crate::{
+        error::MathError,
+        linalg::{matrix::Matrix, rotation::Axis},
+        week5::vector::Vector,
+        week6::angle::{Degrees, Radians},
+    };
+
+    use super::Quaternion;
+
+    #[test]
+    fn algebra() {
+        let (i, j, k) = (
+            Quaternion::new(0.0, 1.0, 0.0, 0.0),
+            Quaternion::new(0.0, 0.0, 1.0, 0.0),
+            Quaternion::new(0.0, 0.0, 0.0, 1.0),
+        );
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, -k);
+        assert_eq!(i * j * k, -Quaternion::IDENTITY);
+
+        let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+        assert_approx_eq!(q.norm(), 30.0_f64.sqrt());
+        assert_approx_eq!(q * q.inverse().unwrap(), Quaternion::IDENTITY);
+        assert_approx_eq!(q.normalized().unwrap().norm(), 1.0);
+        assert_eq!(q.conjugate(), Quaternion::new(1.0, -2.0, -3.0, -4.0));
+        assert_eq!(
+            Quaternion::new(0.0, 0.0, 0.0, 0.0).inverse(),
+            Err(MathError::ZeroVector)
+        );
+    }
+
+    #[test]
+    fn axis_angle() {
+        let axis = Vector(vec![1.0, 1.0, 1.0]);
+        let q = Quaternion::from_axis_angle(&axis, Degrees(120.0)).unwrap();
+
+        assert_approx_eq!(
+            q.rotate(&Vector(vec![1.0, 2.0, 3.0])).unwrap(),
+            Vector(vec![3.0, 1.0, 2.0])
+        );
+        let (back, angle) = q.to_axis_angle();
+        assert_approx_eq!(back, axis.unit_in_direction());
+        assert_approx_eq!(Degrees::from(angle), Degrees(120.0));
+
+        assert_eq!(
+            Quaternion::IDENTITY.to_axis_angle(),
+            (Vector(vec![1.0, 0.0, 0.0]), Radians(0.0))
+        );
+        assert_eq!(
+            Quaternion::from_axis_angle(&Vector(vec![1.0, 0.0]), Degrees(10.0)),
+            Err(MathError::UnsupportedDimension {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn composition() {
+        let about_z = Quaternion::from_axis_angle(&Axis::Z.unit_vector(), Degrees(90.0)).unwrap();
+        let about_x = Quaternion::from_axis_angle(&Axis::X.unit_vector(), Degrees(90.0)).unwrap();
+        let v = Vector(vec![1.0, 0.0, 0.0]);
+
+        // i -> j about z, then j -> k about x
+        let both = about_x * about_z;
+        assert_approx_eq!(both.rotate(&v).unwrap(), Vector(vec![0.0, 0.0, 1.0]));
+        assert_approx_eq!(
+            both.to_rotation_matrix().unwrap(),
+            &Matrix::rotation_about(Axis::X, Degrees(90.0))
+                * &Matrix::rotation_about(Axis::Z, Degrees(90.0))
+        );
+    }
+
+    #[test]
+    fn matrices() {
+        let axis = Vector(vec![2.0, -1.0, 0.5]);
+        // small and large angles, so every branch of from_rotation_matrix gets used
+        for angle in [10.0, 100.0, 179.0, 180.0, 250.0] {
+            let q = Quaternion::from_axis_angle(&axis, Degrees(angle)).unwrap();
+            let m = q.to_rotation_matrix().unwrap();
+            assert_approx_eq!(
+                m,
+                Matrix::rotation_about_axis(&axis, Degrees(angle)).unwrap()
+            );
+
+            let back = Quaternion::from_rotation_matrix(&m).unwrap();
+            assert_approx_eq!(back.to_rotation_matrix().unwrap(), m);
+        }
+        for axis in [Axis::X, Axis::Y, Axis::Z] {
+            let m = Matrix::rotation_about(axis, Radians(PI));
+            let q = Quaternion::from_rotation_matrix(&m).unwrap();
+            assert_approx_eq!(q.to_rotation_matrix().unwrap(), m);
+        }
+
+        // scaling doesn't change the rotation, but there's nothing to scale back from zero
+        assert_approx_eq!(
+            (Quaternion::IDENTITY * 3.0).to_rotation_matrix().unwrap(),
+            Matrix::identity(3)
+        );
+        assert_eq!(
+            Quaternion::new(0.0, 0.0, 0.0, 0.0).to_rotation_matrix(),
+            Err(MathError::ZeroVector)
+        );
+
+        assert_eq!(
+            Quaternion::from_rotation_matrix(&Matrix::identity(2)),
+            Err(MathError::UnsupportedDimension {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn slerp() {
+        let k = Vector(vec![0.0, 0.0, 1.0]);
+        let from = Quaternion::IDENTITY;
+        let to = Quaternion::from_axis_angle(&k, Radians(FRAC_PI_2)).unwrap();
+
+        assert_approx_eq!(from.slerp(&to, 0.0).unwrap(), from);
+        assert_approx_eq!(from.slerp(&to, 1.0).unwrap(), to);
+        assert_approx_eq!(
+            from.slerp(&to, 0.5).unwrap(),
+            Quaternion::from_axis_angle(&k, Degrees(45.0)).unwrap()
+        );
+        // -to is the same rotation, and still goes the short way
+        assert_approx_eq!(
+            from.slerp(&-to, 0.5).unwrap(),
+            Quaternion::from_axis_angle(&k, Degrees(45.0)).unwrap()
+        );
+        assert_approx_eq!(from.slerp(&from, 0.3).unwrap(), from);
+    }
+}
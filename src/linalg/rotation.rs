@@ -0,0 +1,224 @@
+use crate::{
+    error::MathError,
+    week5::{
+        scalar::Scalar,
+        vector::{unit::three_d, Vector},
+    },
+    week6::angle::Angle,
+    week7::point::{Point2, Point3},
+};
+
+use super::matrix::Matrix;
+
+// all rotations are counterclockwise, looking down the axis towards the origin
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis {
+    // 0 for x, 1 for y and 2 for z
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn unit_vector(&self) -> Vector {
+        match self {
+            Axis::X => three_d::i(),
+            Axis::Y => three_d::j(),
+            Axis::Z => three_d::k(),
+        }
+    }
+}
+
+impl Matrix {
+    pub fn rotation_2d(angle: impl Angle) -> Matrix {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        Matrix::new(2, 2, vec![cos, -sin, sin, cos]).unwrap()
+    }
+
+    pub fn rotation_about(axis: Axis, angle: impl Angle) -> Matrix {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let entries = match axis {
+            Axis::X => vec![1.0, 0.0, 0.0, 0.0, cos, -sin, 0.0, sin, cos],
+            Axis::Y => vec![cos, 0.0, sin, 0.0, 1.0, 0.0, -sin, 0.0, cos],
+            Axis::Z => vec![cos, -sin, 0.0, sin, cos, 0.0, 0.0, 0.0, 1.0],
+        };
+        Matrix::new(3, 3, entries).unwrap()
+    }
+
+    // rodrigues: I + sin(angle) K + (1 - cos(angle)) K^2, where K is the cross product matrix of
+    // the unit axis
+    pub fn rotation_about_axis(axis: &Vector, angle: impl Angle) -> Result<Matrix, MathError> {
+        let k = unit_axis(axis)?;
+        let cross = Matrix::new(
+            3,
+            3,
+            vec![0.0, -k[2], k[1], k[2], 0.0, -k[0], -k[1], k[0], 0.0],
+        )?;
+        let squared = &cross * &cross;
+        Ok(Matrix::identity(3) + cross * angle.sin() + squared * (1.0 - angle.cos()))
+    }
+}
+
+impl Vector {
+    pub fn rotated(&self, angle: impl Angle) -> Result<Vector, MathError> {
+        if self.0.len() != 2 {
+            return Err(MathError::UnsupportedDimension {
+                expected: 2,
+                found: self.0.len(),
+            });
+        }
+        Matrix::rotation_2d(angle).apply(self)
+    }
+
+    // rodrigues: v cos(angle) + (k x v) sin(angle) + k (k . v)(1 - cos(angle))
+    pub fn rotated_about(&self, axis: &Vector, angle: impl Angle) -> Result<Vector, MathError> {
+        let k = Vector(unit_axis(axis)?.to_vec());
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let along = k.checked_dot(self)?;
+        Ok(self.multiplied(Scalar(cos))
+            + k.crossed(self)?.multiplied(Scalar(sin))
+            + k.multiplied(along * (1.0 - cos)))
+    }
+}
+
+impl Point2 {
+    // about the origin
+    pub fn rotated(self, angle: impl Angle) -> Point2 {
+        Point2::from_vector(&self.to_vector().rotated(angle).unwrap()).unwrap()
+    }
+}
+
+impl Point3 {
+    // about an axis through the origin
+    pub fn rotated_about(self, axis: &Vector, angle: impl Angle) -> Result<Point3, MathError> {
+        Point3::from_vector(&self.to_vector().rotated_about(axis, angle)?)
+    }
+}
+
+fn unit_axis(axis: &Vector) -> Result<[f64; 3], MathError> {
+    if axis.0.len() != 3 {
+        return Err(MathError::UnsupportedDimension {
+            expected: 3,
+            found: axis.0.len(),
+        });
+    }
+    if axis.magnitude().0 == 0.0 {
+        return Err(MathError::ZeroVector);
+    }
+    let unit = axis.unit_in_direction();
+    Ok([unit.0[0], unit.0[1], unit.0[2]])
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::{
+        error::MathError,
+        linalg::matrix::Matrix,
+        week5::vector::Vector,
+        week6::angle::{Degrees, Radians, Turns},
+        week7::point::{Point2, Point3},
+    };
+
+    use super::Axis;
+
+    #[test]
+    fn two_d() {
+        assert_approx_eq!(
+            Vector(vec![1.0, 0.0]).rotated(Degrees(90.0)).unwrap(),
+            Vector(vec![0.0, 1.0])
+        );
+        assert_approx_eq!(Point2(1.0, 1.0).rotated(Turns(0.5)), Point2(-1.0, -1.0));
+        assert_approx_eq!(
+            &Matrix::rotation_2d(Degrees(30.0)) * &Matrix::rotation_2d(Degrees(60.0)),
+            Matrix::rotation_2d(Radians(FRAC_PI_2))
+        );
+        assert_eq!(
+            Vector(vec![1.0, 0.0, 0.0]).rotated(Degrees(90.0)),
+            Err(MathError::UnsupportedDimension {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn coordinate_axes() {
+        let quarter = Degrees(90.0);
+        let (i, j, k) = (
+            Axis::X.unit_vector(),
+            Axis::Y.unit_vector(),
+            Axis::Z.unit_vector(),
+        );
+
+        // x -> y -> z -> x
+        assert_approx_eq!(
+            Matrix::rotation_about(Axis::Z, quarter).apply(&i).unwrap(),
+            j
+        );
+        assert_approx_eq!(
+            Matrix::rotation_about(Axis::X, quarter).apply(&j).unwrap(),
+            k
+        );
+        assert_approx_eq!(
+            Matrix::rotation_about(Axis::Y, quarter).apply(&k).unwrap(),
+            i
+        );
+
+        for axis in [Axis::X, Axis::Y, Axis::Z] {
+            assert_approx_eq!(
+                Matrix::rotation_about(axis, Degrees(-40.0)),
+                Matrix::rotation_about_axis(&axis.unit_vector(), Degrees(-40.0)).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn rodrigues() {
+        // a third of a turn about (1, 1, 1) cycles the coordinates
+        let diagonal = Vector(vec![1.0, 1.0, 1.0]);
+        let v = Vector(vec![1.0, 2.0, 3.0]);
+        let third = Turns(1.0 / 3.0);
+
+        assert_approx_eq!(
+            v.rotated_about(&diagonal, third).unwrap(),
+            Vector(vec![3.0, 1.0, 2.0])
+        );
+        assert_approx_eq!(
+            Matrix::rotation_about_axis(&diagonal, third)
+                .unwrap()
+                .apply(&v)
+                .unwrap(),
+            Vector(vec![3.0, 1.0, 2.0])
+        );
+        assert_approx_eq!(
+            Point3(1.0, 2.0, 3.0)
+                .rotated_about(&diagonal, third)
+                .unwrap(),
+            Point3(3.0, 1.0, 2.0)
+        );
+
+        // the axis itself stays put and lengths are preserved
+        assert_approx_eq!(
+            diagonal.rotated_about(&diagonal, Degrees(73.0)).unwrap(),
+            diagonal
+        );
+        assert_approx_eq!(
+            v.rotated_about(&Vector(vec![2.0, -1.0, 0.5]), Degrees(73.0))
+                .unwrap()
+                .magnitude(),
+            v.magnitude()
+        );
+
+        assert_eq!(
+            v.rotated_about(&Vector(vec![0.0, 0.0, 0.0]), third),
+            Err(MathError::ZeroVector)
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use crate::error::MathError;
+use crate::week5::vector::Vector;
+
+use super::matrix::Matrix;
+
+impl Matrix {
+    pub fn inverse(&self) -> Result<Matrix, MathError> {
+        let lu = self.lu()?;
+        let n = self.rows();
+        // column j of the inverse solves Ax = e_j
+        let columns = (0..n)
+            .map(|j| {
+                let mut e = vec![0.0; n];
+                e[j] = 1.0;
+                lu.solve(&Vector(e))
+            })
+            .collect::<Result<Vec<Vector>, MathError>>()?;
+        Matrix::from_columns(columns)
+    }
+
+    pub fn rank(&self) -> usize {
+        self.row_reduce().rank()
+    }
+
+    // basis for the solutions of Ax = 0, one vector per free variable
+    pub fn null_space(&self) -> Vec<Vector> {
+        self.row_reduce().null_space_basis(self.cols())
+    }
+
+    // basis made of the columns of the original matrix that hold pivots
+    pub fn column_space(&self) -> Vec<Vector> {
+        self.row_reduce()
+            .pivot_columns
+            .into_iter()
+            .map(|col| self.column_vector(col))
+            .collect()
+    }
+}
+
+pub fn is_linearly_independent(vectors: &[Vector]) -> Result<bool, MathError> {
+    if vectors.is_empty() {
+        return Ok(true);
+    }
+    Ok(Matrix::from_columns(vectors.to_vec())?.rank() == vectors.len())
+}
+
+// whether v is a linear combination of the given vectors
+pub fn span_contains(vectors: &[Vector], v: &Vector) -> Result<bool, MathError> {
+    if vectors.is_empty() {
+        return Ok(v.0.iter().all(|x| *x == 0.0));
+    }
+
+    let mut columns = vectors.to_vec();
+    let rank = Matrix::from_columns(columns.clone())?.rank();
+    columns.push(v.clone());
+    Ok(Matrix::from_columns(columns)?.rank() == rank)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{error::MathError, week5::vector::Vector};
+
+    use super::{is_linearly_independent, span_contains, Matrix};
+
+    fn m(rows: Vec<Vec<f64>>) -> Matrix {
+        Matrix::from_rows(rows.into_iter().map(Vector).collect()).unwrap()
+    }
+
+    #[test]
+    fn inverse() {
+        let a = m(vec![
+            vec![2.0, 1.0, 1.0],
+            vec![1.0, 3.0, 2.0],
+            vec![1.0, 0.0, 0.0],
+        ]);
+        let inverse = a.inverse().unwrap();
+
+        assert_approx_eq!(&a * &inverse, Matrix::identity(3));
+        assert_approx_eq!(
+            inverse,
+            m(vec![
+                vec![0.0, 0.0, 1.0],
+                vec![-2.0, 1.0, 3.0],
+                vec![3.0, -1.0, -5.0],
+            ])
+        );
+        assert_eq!(
+            m(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).inverse(),
+            Err(MathError::SingularMatrix)
+        );
+    }
+
+    #[test]
+    fn spaces() {
+        let a = m(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![2.0, 4.0, 6.0],
+            vec![1.0, 0.0, 1.0],
+        ]);
+
+        assert_eq!(a.rank(), 2);
+        assert_eq!(
+            a.column_space(),
+            vec![Vector(vec![1.0, 2.0, 1.0]), Vector(vec![2.0, 4.0, 0.0])]
+        );
+
+        let null_space = a.null_space();
+        assert_eq!(null_space.len(), 1);
+        assert_approx_eq!(&a * &null_space[0], Vector(vec![0.0, 0.0, 0.0]));
+        assert!(Matrix::identity(3).null_space().is_empty());
+
+        // every entry below 1 doesn't make anything dependent
+        let small = a.clone() * 1e-14;
+        assert_eq!(small.rank(), 2);
+        assert_eq!(small.null_space().len(), 1);
+        assert_eq!(small.column_space().len(), 2);
+        let small_identity = Matrix::identity(3) * 1e-13;
+        assert_eq!(small_identity.rank(), 3);
+        assert!(small_identity.null_space().is_empty());
+        assert_eq!(Matrix::zero(2, 3).null_space().len(), 3);
+    }
+
+    #[test]
+    fn independence() {
+        let u = Vector(vec![1.0, 2.0, 3.0]);
+        let v = Vector(vec![0.0, 1.0, 1.0]);
+        let coplanar = Vector(vec![2.0, 5.0, 7.0]);
+
+        assert_eq!(is_linearly_independent(&[u.clone(), v.clone()]), Ok(true));
+        assert_eq!(
+            is_linearly_independent(&[u.clone(), v.clone(), coplanar.clone()]),
+            Ok(false)
+        );
+        assert_eq!(span_contains(&[u.clone(), v.clone()], &coplanar), Ok(true));
+        assert_eq!(
+            span_contains(&[u.clone(), v.clone()], &Vector(vec![0.0, 0.0, 1.0])),
+            Ok(false)
+        );
+        let shrink = |v: &Vector| v.clone() * 1e-13;
+        assert_eq!(is_linearly_independent(&[shrink(&u), shrink(&v)]), Ok(true));
+        assert_eq!(
+            span_contains(
+                &[shrink(&u), shrink(&v)],
+                &shrink(&Vector(vec![0.0, 0.0, 1.0]))
+            ),
+            Ok(false)
+        );
+
+        assert_eq!(
+            is_linearly_independent(&[u, Vector(vec![1.0, 2.0])]),
+            Err(MathError::DimensionMismatch { left: 3, right: 2 })
+        );
+    }
+}
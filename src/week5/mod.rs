@@ -1,2 +1,3 @@
 pub mod scalar;
 pub mod vector;
+pub mod vector_n;
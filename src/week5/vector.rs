@@ -1,6 +1,6 @@
 use std::{ops::*, vec};
 
-use crate::week5::scalar::Scalar;
+use crate::{error::MathError, week5::scalar::Scalar};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Vector(pub vec::Vec<f64>);
@@ -24,6 +24,18 @@ impl Vector {
         Vector(self.0.into_iter().map(|x| x / magnitude.0).collect())
     }
 
+    // unit_in_direction, except the zero vector is an error instead of a vector of NaN
+    pub fn try_unit(&self) -> Result<Vector, MathError> {
+        self.clone().try_into_unit()
+    }
+
+    pub fn try_into_unit(self) -> Result<Vector, MathError> {
+        if self.magnitude().0 == 0.0 {
+            return Err(MathError::ZeroVector);
+        }
+        Ok(self.into_unit_in_direction())
+    }
+
     pub fn invert(&mut self) {
         self.0.iter_mut().for_each(|x| *x *= -1.0);
     }
@@ -36,9 +48,12 @@ impl Vector {
         Vector(self.0.iter().map(|x| -x).collect())
     }
 
-    pub fn added(&self, other: &Self) -> Result<Self, &str> {
+    pub fn added(&self, other: &Self) -> Result<Self, MathError> {
         if self.0.len() != other.0.len() {
-            return Err("Mismatched dimensions");
+            return Err(MathError::DimensionMismatch {
+                left: self.0.len(),
+                right: other.0.len(),
+            });
         }
         Ok(Vector(
             self.0
@@ -49,10 +64,18 @@ impl Vector {
         ))
     }
 
-    pub fn subtracted(&self, other: &Self) -> Result<Self, &str> {
+    pub fn subtracted(&self, other: &Self) -> Result<Self, MathError> {
         self.added(&other.inverted())
     }
 
+    pub fn checked_add(self, rhs: Self) -> Result<Self, MathError> {
+        self.added(&rhs)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, MathError> {
+        self.subtracted(&rhs)
+    }
+
     pub fn multiply(&mut self, scalar: Scalar) {
         self.0.iter_mut().for_each(|x| *x *= scalar.0);
     }
@@ -72,7 +95,7 @@ impl Add for Vector {
     type Output = Vector;
 
     fn add(self, rhs: Self) -> Self::Output {
-        self.added(&rhs).unwrap()
+        self.checked_add(rhs).unwrap()
     }
 }
 
@@ -80,7 +103,7 @@ impl Sub for Vector {
     type Output = Vector;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        self.subtracted(&rhs).unwrap()
+        self.checked_sub(rhs).unwrap()
     }
 }
 
@@ -0,0 +1,274 @@
+use std::ops::*;
+
+use crate::error::MathError;
+use crate::week5::{scalar::Scalar, vector::Vector};
+use crate::week6::angle::Radians;
+
+// same as Vector, but the dimension is part of the type so mismatches are caught by the compiler
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VectorN<const N: usize>(pub [f64; N]);
+
+pub type Vector2 = VectorN<2>;
+pub type Vector3 = VectorN<3>;
+
+impl<const N: usize> VectorN<N> {
+    pub const ZERO: VectorN<N> = VectorN([0.0; N]);
+
+    pub fn magnitude_squared(&self) -> Scalar {
+        Scalar(self.0.iter().map(|x| x * x).sum::<f64>())
+    }
+
+    pub fn magnitude(&self) -> Scalar {
+        Scalar(self.magnitude_squared().0.sqrt())
+    }
+
+    pub fn unit_in_direction(&self) -> VectorN<N> {
+        let magnitude = self.magnitude();
+        VectorN(self.0.map(|x| x / magnitude.0))
+    }
+
+    pub fn invert(&mut self) {
+        self.0.iter_mut().for_each(|x| *x *= -1.0);
+    }
+
+    pub fn inverted(&self) -> VectorN<N> {
+        VectorN(self.0.map(|x| -x))
+    }
+
+    pub fn added(&self, other: &Self) -> VectorN<N> {
+        VectorN(std::array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+
+    pub fn subtracted(&self, other: &Self) -> VectorN<N> {
+        self.added(&other.inverted())
+    }
+
+    pub fn multiply(&mut self, scalar: Scalar) {
+        self.0.iter_mut().for_each(|x| *x *= scalar.0);
+    }
+
+    pub fn multiplied(&self, scalar: Scalar) -> VectorN<N> {
+        VectorN(self.0.map(|x| x * scalar.0))
+    }
+
+    pub fn dotted(&self, other: &Self) -> Scalar {
+        Scalar(
+            self.0
+                .iter()
+                .zip(other.0.iter())
+                .map(|(x1, x2)| x1 * x2)
+                .sum(),
+        )
+    }
+
+    pub fn projected_on(&self, base: &Self) -> Self {
+        base.multiplied(self.dotted(base) / base.magnitude_squared())
+    }
+
+    pub fn angle_between(&self, other: &Self) -> Radians {
+        Radians(
+            (self.dotted(other) / (self.magnitude() * other.magnitude()))
+                .0
+                .acos(),
+        )
+    }
+}
+
+impl Vector3 {
+    pub fn crossed(&self, other: &Self) -> Self {
+        let u = &self.0;
+        let v = &other.0;
+        VectorN([
+            (u[1] * v[2]) - (u[2] * v[1]),
+            -((u[0] * v[2]) - (u[2] * v[0])),
+            (u[0] * v[1]) - (u[1] * v[0]),
+        ])
+    }
+}
+
+// ========== conversions ==========
+
+impl<const N: usize> From<VectorN<N>> for Vector {
+    fn from(v: VectorN<N>) -> Self {
+        Vector(v.0.to_vec())
+    }
+}
+
+impl<const N: usize> TryFrom<Vector> for VectorN<N> {
+    type Error = MathError;
+
+    fn try_from(v: Vector) -> Result<Self, Self::Error> {
+        VectorN::try_from(&v)
+    }
+}
+
+impl<const N: usize> TryFrom<&Vector> for VectorN<N> {
+    type Error = MathError;
+
+    fn try_from(v: &Vector) -> Result<Self, Self::Error> {
+        v.0.as_slice()
+            .try_into()
+            .map(VectorN)
+            .map_err(|_| MathError::DimensionMismatch {
+                left: N,
+                right: v.0.len(),
+            })
+    }
+}
+
+// ========== vector - vector operations ==========
+
+impl<const N: usize> Add for VectorN<N> {
+    type Output = VectorN<N>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.added(&rhs)
+    }
+}
+
+impl<const N: usize> Sub for VectorN<N> {
+    type Output = VectorN<N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.subtracted(&rhs)
+    }
+}
+
+// dot product
+impl<const N: usize> Mul<VectorN<N>> for VectorN<N> {
+    type Output = Scalar;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.dotted(&rhs)
+    }
+}
+
+// projection
+
+impl<const N: usize> Shl<VectorN<N>> for VectorN<N> {
+    type Output = VectorN<N>;
+
+    fn shl(self, rhs: VectorN<N>) -> Self::Output {
+        rhs.projected_on(&self)
+    }
+}
+
+impl<const N: usize> Shr<VectorN<N>> for VectorN<N> {
+    type Output = VectorN<N>;
+
+    fn shr(self, rhs: VectorN<N>) -> Self::Output {
+        self.projected_on(&rhs)
+    }
+}
+
+// cross product, only defined for three dimensions
+
+impl BitXor<Vector3> for Vector3 {
+    type Output = Vector3;
+
+    fn bitxor(self, rhs: Vector3) -> Self::Output {
+        self.crossed(&rhs)
+    }
+}
+
+impl BitXor<&Vector3> for &Vector3 {
+    type Output = Vector3;
+
+    fn bitxor(self, rhs: &Vector3) -> Self::Output {
+        self.crossed(rhs)
+    }
+}
+
+// ========== vector - scalar operations ==========
+
+impl<const N: usize> Mul<Scalar> for VectorN<N> {
+    type Output = VectorN<N>;
+
+    fn mul(self, rhs: Scalar) -> Self::Output {
+        self.multiplied(rhs)
+    }
+}
+
+impl<const N: usize> Mul<f64> for VectorN<N> {
+    type Output = VectorN<N>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.multiplied(Scalar(rhs))
+    }
+}
+
+impl<const N: usize> Div<Scalar> for VectorN<N> {
+    type Output = VectorN<N>;
+
+    fn div(self, rhs: Scalar) -> Self::Output {
+        self * (1.0 / rhs.0)
+    }
+}
+
+impl<const N: usize> Div<f64> for VectorN<N> {
+    type Output = VectorN<N>;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        self * (1.0 / rhs)
+    }
+}
+
+impl<const N: usize> Neg for VectorN<N> {
+    type Output = VectorN<N>;
+
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::MathError;
+    use crate::week5::{scalar::Scalar, vector::Vector};
+
+    use super::{Vector2, Vector3, VectorN};
+
+    #[test]
+    fn operations() {
+        let u = VectorN([-2.0, 1.0, -2.0]);
+        let v = VectorN([1.0, 2.0, 1.0]);
+
+        assert_eq!((u * 3.0) - (v * 2.0), VectorN([-8.0, -1.0, -8.0]));
+        assert_eq!(u * v, Scalar(-2.0));
+        assert_eq!(Vector2::ZERO.magnitude(), Scalar(0.0));
+    }
+
+    #[test]
+    fn cross() {
+        let i = VectorN([1.0, 0.0, 0.0]);
+        let j = VectorN([0.0, 1.0, 0.0]);
+        let k = VectorN([0.0, 0.0, 1.0]);
+
+        assert_eq!(i ^ j, k);
+        assert_eq!(
+            VectorN([2.0, 1.0, 3.0]) ^ VectorN([-1.0, 2.0, 2.0]),
+            VectorN([-4.0, -7.0, 5.0])
+        );
+    }
+
+    #[test]
+    fn projection() {
+        let u = VectorN([7.0, 0.0, 15.0]);
+        let v = VectorN([0.0, 4.0, -2.0]);
+
+        assert_eq!(v << u, VectorN([0.0, -6.0, 3.0]));
+    }
+
+    #[test]
+    fn conversions() {
+        let v = Vector(vec![1.0, 2.0, 3.0]);
+        let fixed: Vector3 = v.clone().try_into().unwrap();
+
+        assert_eq!(fixed, VectorN([1.0, 2.0, 3.0]));
+        assert_eq!(Vector::from(fixed), v);
+        assert_eq!(
+            Vector2::try_from(&v),
+            Err(MathError::DimensionMismatch { left: 2, right: 3 })
+        );
+    }
+}
@@ -1,13 +1,124 @@
-use std::f64::consts::PI;
+use std::f64::consts::{PI, TAU};
+
+use crate::approx::{ApproxEq, Tolerance};
 
 pub trait Angle: Into<Degrees> + Into<Radians> + Copy {
-    fn to_unit_circle(self) -> Self;
+    // how big a full turn is in this unit
+    const FULL_TURN: f64;
+
+    fn value(self) -> f64;
+    fn from_value(value: f64) -> Self;
+
+    // into [0, full turn)
+    fn to_unit_circle(self) -> Self {
+        Self::from_value(wrap(self.value(), Self::FULL_TURN))
+    }
+
+    // into (-half turn, half turn]
+    fn to_signed(self) -> Self {
+        let wrapped = wrap(self.value(), Self::FULL_TURN);
+        if wrapped > Self::FULL_TURN / 2.0 {
+            Self::from_value(wrapped - Self::FULL_TURN)
+        } else {
+            Self::from_value(wrapped)
+        }
+    }
+
+    fn is_eq<T: Angle>(&self, other: T) -> bool {
+        Into::<Radians>::into(*self).0 == Into::<Radians>::into(other).0
+    }
+
+    // whether the two angles end up in the same place on the unit circle
+    fn is_equivalent<T: Angle>(&self, other: T) -> bool {
+        self.is_equivalent_with(other, Tolerance::DEFAULT)
+    }
+
+    // the tolerance is in radians
+    fn is_equivalent_with<T: Angle>(&self, other: T, tolerance: Tolerance) -> bool {
+        let difference = Into::<Radians>::into(*self) - Into::<Radians>::into(other);
+        difference.to_signed().0.approx_eq_with(&0.0, tolerance)
+    }
+
+    fn sin(self) -> f64 {
+        Into::<Radians>::into(self).0.sin()
+    }
+
+    fn cos(self) -> f64 {
+        Into::<Radians>::into(self).0.cos()
+    }
+
+    fn tan(self) -> f64 {
+        Into::<Radians>::into(self).0.tan()
+    }
+}
+
+// value mod full, always in [0, full)
+fn wrap(value: f64, full: f64) -> f64 {
+    let wrapped = value.rem_euclid(full);
+    // tiny negative values round up to exactly full
+    if wrapped == full {
+        0.0
+    } else {
+        wrapped
+    }
+}
+
+// same unit arithmetic, mixing units has to go through an explicit conversion
+macro_rules! angle_arithmetic {
+    ($unit:ident) => {
+        impl std::ops::Add for $unit {
+            type Output = $unit;
+
+            fn add(self, rhs: $unit) -> Self::Output {
+                $unit(self.0 + rhs.0)
+            }
+        }
+
+        impl std::ops::Sub for $unit {
+            type Output = $unit;
+
+            fn sub(self, rhs: $unit) -> Self::Output {
+                $unit(self.0 - rhs.0)
+            }
+        }
+
+        impl std::ops::Neg for $unit {
+            type Output = $unit;
+
+            fn neg(self) -> Self::Output {
+                $unit(-self.0)
+            }
+        }
+
+        impl std::ops::Mul<f64> for $unit {
+            type Output = $unit;
+
+            fn mul(self, rhs: f64) -> Self::Output {
+                $unit(self.0 * rhs)
+            }
+        }
+
+        impl std::ops::Mul<$unit> for f64 {
+            type Output = $unit;
+
+            fn mul(self, rhs: $unit) -> Self::Output {
+                $unit(self * rhs.0)
+            }
+        }
+
+        impl std::ops::Div<f64> for $unit {
+            type Output = $unit;
 
-    fn is_eq<T: Angle>(&self, other: T) -> bool;
-    fn is_equivalent<T: Angle>(&self, other: T) -> bool;
+            fn div(self, rhs: f64) -> Self::Output {
+                $unit(self.0 / rhs)
+            }
+        }
+    };
 }
 
-#[derive(Clone, Copy, Debug)]
+pub(crate) use angle_arithmetic;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Radians(pub f64);
 
 impl From<Degrees> for Radians {
@@ -17,20 +128,20 @@ impl From<Degrees> for Radians {
 }
 
 impl Angle for Radians {
-    fn to_unit_circle(self) -> Self {
-        Radians(self.0 % (2.0 * PI))
-    }
+    const FULL_TURN: f64 = TAU;
 
-    fn is_eq<T: Angle>(&self, other: T) -> bool {
-        self.0 == Into::<Radians>::into(other).0
+    fn value(self) -> f64 {
+        self.0
     }
 
-    fn is_equivalent<T: Angle>(&self, other: T) -> bool {
-        ((self.to_unit_circle()).0) == (Into::<Radians>::into(other).to_unit_circle().0)
+    fn from_value(value: f64) -> Self {
+        Radians(value)
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+angle_arithmetic!(Radians);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Degrees(pub f64);
 
 impl From<Radians> for Degrees {
@@ -40,31 +151,166 @@ impl From<Radians> for Degrees {
 }
 
 impl Angle for Degrees {
-    fn to_unit_circle(self) -> Self {
-        todo!()
+    const FULL_TURN: f64 = 360.0;
+
+    fn value(self) -> f64 {
+        self.0
     }
 
-    fn is_eq<T>(&self, other: T) -> bool
-    where
-        T: Angle,
-    {
-        self.0 == Into::<Degrees>::into(other.clone()).0
+    fn from_value(value: f64) -> Self {
+        Degrees(value)
     }
+}
 
-    fn is_equivalent<T: Angle>(&self, other: T) -> bool {
-        Into::<Radians>::into(other.clone()).is_equivalent(other)
+angle_arithmetic!(Degrees);
+
+// 400 to a full turn, so a right angle is 100
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gradians(pub f64);
+
+impl From<Radians> for Gradians {
+    fn from(rad: Radians) -> Gradians {
+        Gradians(rad.0 * 200.0 / PI)
     }
 }
 
+impl From<Gradians> for Radians {
+    fn from(grad: Gradians) -> Radians {
+        Radians(grad.0 * PI / 200.0)
+    }
+}
+
+impl From<Gradians> for Degrees {
+    fn from(grad: Gradians) -> Degrees {
+        Radians::from(grad).into()
+    }
+}
+
+impl Angle for Gradians {
+    const FULL_TURN: f64 = 400.0;
+
+    fn value(self) -> f64 {
+        self.0
+    }
+
+    fn from_value(value: f64) -> Self {
+        Gradians(value)
+    }
+}
+
+angle_arithmetic!(Gradians);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Turns(pub f64);
+
+impl From<Radians> for Turns {
+    fn from(rad: Radians) -> Turns {
+        Turns(rad.0 / TAU)
+    }
+}
+
+impl From<Turns> for Radians {
+    fn from(turns: Turns) -> Radians {
+        Radians(turns.0 * TAU)
+    }
+}
+
+impl From<Turns> for Degrees {
+    fn from(turns: Turns) -> Degrees {
+        Radians::from(turns).into()
+    }
+}
+
+impl Angle for Turns {
+    const FULL_TURN: f64 = 1.0;
+
+    fn value(self) -> f64 {
+        self.0
+    }
+
+    fn from_value(value: f64) -> Self {
+        Turns(value)
+    }
+}
+
+angle_arithmetic!(Turns);
+
 #[cfg(test)]
 mod tests {
-    use std::f64::consts::PI;
+    use std::f64::consts::{FRAC_PI_2, PI};
 
-    use super::{Angle, Degrees, Radians};
+    use crate::approx::Tolerance;
+
+    use super::{Angle, Degrees, Gradians, Radians, Turns};
 
     #[test]
     fn equivalence() {
-        assert_eq!(true, Degrees(240.0).is_equivalent(Degrees(-60.0)));
-        assert_eq!(true, Radians(1.0).is_equivalent(Radians((2.0 * PI) + 1.0)));
+        assert!(Degrees(300.0).is_equivalent(Degrees(-60.0)));
+        assert!(!Degrees(240.0).is_equivalent(Degrees(-60.0)));
+        assert!(Radians(1.0).is_equivalent(Radians((2.0 * PI) + 1.0)));
+        assert!(Degrees(-90.0).is_equivalent(Radians(3.0 * FRAC_PI_2)));
+        assert!(!Degrees(90.0).is_equivalent(Radians(3.0 * FRAC_PI_2)));
+
+        // right across the seam
+        assert!(Degrees(359.9999999999).is_equivalent(Degrees(0.0)));
+        assert!(!Degrees(359.9).is_equivalent(Degrees(0.0)));
+        assert!(Degrees(359.9).is_equivalent_with(Degrees(0.0), Tolerance::absolute(1e-2)));
+    }
+
+    #[test]
+    fn normalization() {
+        assert_approx_eq!(Degrees(-60.0).to_unit_circle(), Degrees(300.0));
+        assert_approx_eq!(Degrees(720.0).to_unit_circle(), Degrees(0.0));
+        assert_approx_eq!(Degrees(270.0).to_signed(), Degrees(-90.0));
+        assert_approx_eq!(Degrees(180.0).to_signed(), Degrees(180.0));
+        assert_approx_eq!(Degrees(-180.0).to_signed(), Degrees(180.0));
+
+        assert_approx_eq!(
+            Radians(-FRAC_PI_2).to_unit_circle(),
+            Radians(3.0 * FRAC_PI_2)
+        );
+        assert_approx_eq!(Radians(5.0 * PI).to_signed(), Radians(PI));
+        assert_approx_eq!(Radians(-1e-20).to_unit_circle(), Radians(0.0));
+
+        for value in [-1000.0, -1.0, 0.0, 3.0, 7.0, 1e6] {
+            let on_circle = Radians(value).to_unit_circle().0;
+            assert!((0.0..2.0 * PI).contains(&on_circle));
+            let signed = Radians(value).to_signed().0;
+            assert!(signed > -PI && signed <= PI);
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(Degrees(30.0) + Degrees(60.0), Degrees(90.0));
+        assert_eq!(Degrees(30.0) - Degrees(60.0), Degrees(-30.0));
+        assert_eq!(-Radians(1.0), Radians(-1.0));
+        assert_eq!(Radians(1.0) * 3.0, Radians(3.0));
+        assert_eq!(2.0 * Degrees(45.0), Degrees(90.0));
+        assert_eq!(Degrees(90.0) / 2.0, Degrees(45.0));
+    }
+
+    #[test]
+    fn other_units() {
+        assert_approx_eq!(Radians::from(Gradians(100.0)), Radians(FRAC_PI_2));
+        assert_approx_eq!(Degrees::from(Gradians(50.0)), Degrees(45.0));
+        assert_approx_eq!(Gradians::from(Radians(PI)), Gradians(200.0));
+        assert_approx_eq!(Degrees::from(Turns(0.25)), Degrees(90.0));
+        assert_approx_eq!(Turns::from(Radians::from(Gradians(300.0))), Turns(0.75));
+
+        assert_approx_eq!(Gradians(-100.0).to_unit_circle(), Gradians(300.0));
+        assert_approx_eq!(Turns(1.75).to_signed(), Turns(-0.25));
+        assert!(Turns(0.5).is_equivalent(Degrees(-180.0)));
+        assert!(Gradians(100.0).is_equivalent(Turns(1.25)));
+        assert_approx_eq!(Gradians(100.0).sin(), 1.0);
+        assert_eq!(Turns(0.5) + Turns(0.25), Turns(0.75));
+    }
+
+    #[test]
+    fn trigonometry() {
+        assert_approx_eq!(Degrees(30.0).sin(), 0.5);
+        assert_approx_eq!(Degrees(60.0).cos(), 0.5);
+        assert_approx_eq!(Degrees(45.0).tan(), 1.0);
+        assert_approx_eq!(Radians(PI).cos(), -1.0);
     }
 }
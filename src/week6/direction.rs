@@ -0,0 +1,114 @@
+use crate::{
+    approx::{ApproxEq, Tolerance},
+    error::MathError,
+    week5::vector::{unit::three_d, Vector},
+};
+
+use super::angle::{Angle, Radians};
+
+impl Vector {
+    // cos(alpha), cos(beta), cos(gamma): the components of the unit vector in this direction
+    pub fn checked_direction_cosines(&self) -> Result<[f64; 3], MathError> {
+        self.check_direction()?;
+        let unit = self.unit_in_direction();
+        Ok([unit.0[0], unit.0[1], unit.0[2]])
+    }
+
+    pub fn direction_cosines(&self) -> [f64; 3] {
+        self.checked_direction_cosines().unwrap()
+    }
+
+    // alpha, beta, gamma: the angles this makes with i, j and k
+    pub fn checked_direction_angles(&self) -> Result<[Radians; 3], MathError> {
+        self.check_direction()?;
+        Ok([three_d::i(), three_d::j(), three_d::k()].map(|axis| self.angle_between(&axis)))
+    }
+
+    pub fn direction_angles(&self) -> [Radians; 3] {
+        self.checked_direction_angles().unwrap()
+    }
+
+    // the unit vector making these angles with i, j and k
+    pub fn from_direction_angles<A: Angle>(angles: [A; 3]) -> Result<Vector, MathError> {
+        Vector::from_direction_angles_with(angles, Tolerance::DEFAULT)
+    }
+
+    pub fn from_direction_angles_with<A: Angle>(
+        angles: [A; 3],
+        tolerance: Tolerance,
+    ) -> Result<Vector, MathError> {
+        let cosines = angles.map(|angle| angle.cos());
+        let sum: f64 = cosines.iter().map(|c| c * c).sum();
+        if !sum.approx_eq_with(&1.0, tolerance) {
+            return Err(MathError::InvalidDirectionCosines { sum });
+        }
+        Ok(Vector(cosines.to_vec()))
+    }
+
+    fn check_direction(&self) -> Result<(), MathError> {
+        if self.0.len() != 3 {
+            return Err(MathError::UnsupportedDimension {
+                expected: 3,
+                found: self.0.len(),
+            });
+        }
+        if self.magnitude().0 == 0.0 {
+            return Err(MathError::ZeroVector);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        approx::Tolerance,
+        error::MathError,
+        week5::vector::Vector,
+        week6::angle::{Degrees, Radians},
+    };
+
+    #[test]
+    fn cosines_and_angles() {
+        // stewart 12.3 example 8
+        let v = Vector(vec![1.0, 2.0, 3.0]);
+        let root = 14.0_f64.sqrt();
+
+        let cosines = v.direction_cosines();
+        assert_approx_eq!(cosines[..], [1.0 / root, 2.0 / root, 3.0 / root][..]);
+        let [alpha, beta, gamma] = v.direction_angles();
+        let close = Tolerance::absolute(1e-9);
+        assert_approx_eq!(Degrees::from(alpha), Degrees(74.498640433063), close);
+        assert_approx_eq!(Degrees::from(beta), Degrees(57.688466762576), close);
+        assert_approx_eq!(Degrees::from(gamma), Degrees(36.699225200490), close);
+
+        assert_eq!(
+            Vector(vec![1.0, 2.0]).checked_direction_angles(),
+            Err(MathError::UnsupportedDimension {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Vector(vec![0.0, 0.0, 0.0]).checked_direction_cosines(),
+            Err(MathError::ZeroVector)
+        );
+    }
+
+    #[test]
+    fn from_angles() {
+        let v = Vector::from_direction_angles([Degrees(60.0), Degrees(45.0), Degrees(60.0)]);
+        assert_approx_eq!(v.unwrap(), Vector(vec![0.5, 0.5_f64.sqrt(), 0.5]));
+
+        let w = Vector(vec![-2.0, 1.0, 2.0]);
+        assert_approx_eq!(
+            Vector::from_direction_angles(w.direction_angles()).unwrap(),
+            w.unit_in_direction()
+        );
+
+        assert!(matches!(
+            Vector::from_direction_angles([Radians(0.0); 3]),
+            Err(MathError::InvalidDirectionCosines { sum }) if sum == 3.0
+        ));
+    }
+}
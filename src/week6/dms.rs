@@ -0,0 +1,231 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::MathError;
+
+use super::angle::{angle_arithmetic, Angle, Degrees, Radians};
+
+// degrees, minutes and seconds, kept as decimal degrees so nothing is lost between conversions
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dms(pub f64);
+
+impl Dms {
+    // the magnitude is the sum of the parts, use Neg for angles below zero
+    pub fn new(degrees: u32, minutes: u32, seconds: f64) -> Dms {
+        Dms(degrees as f64 + minutes as f64 / 60.0 + seconds / 3600.0)
+    }
+
+    // (is negative, degrees, minutes, seconds), rounded to a thousandth of a second
+    pub fn parts(&self) -> (bool, u32, u32, f64) {
+        let milliseconds = (self.0.abs() * 3_600_000.0).round() as u64;
+        (
+            self.0 < 0.0,
+            (milliseconds / 3_600_000) as u32,
+            (milliseconds / 60_000 % 60) as u32,
+            (milliseconds % 60_000) as f64 / 1000.0,
+        )
+    }
+
+    // eg. 40°26'46"N
+    pub fn to_latitude(&self) -> String {
+        self.with_hemisphere('N', 'S')
+    }
+
+    // eg. 79°58'56"W
+    pub fn to_longitude(&self) -> String {
+        self.with_hemisphere('E', 'W')
+    }
+
+    fn with_hemisphere(&self, positive: char, negative: char) -> String {
+        let (is_negative, degrees, minutes, seconds) = self.parts();
+        format!(
+            "{}°{}'{}\"{}",
+            degrees,
+            minutes,
+            seconds,
+            if is_negative { negative } else { positive }
+        )
+    }
+}
+
+impl Display for Dms {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (is_negative, degrees, minutes, seconds) = self.parts();
+        let sign = if is_negative { "-" } else { "" };
+        write!(f, "{}{}°{}'{}\"", sign, degrees, minutes, seconds)
+    }
+}
+
+// accepts 40°26'46"N, -40°26.5', 12.5°, 40d 26' 46" S and the like:
+// minutes and seconds are optional, and a hemisphere letter replaces the sign
+impl FromStr for Dms {
+    type Err = MathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| MathError::Parse(format!("`{}` {}", s, reason));
+
+        let text: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        let (text, hemisphere) = match text.chars().last() {
+            Some(c @ ('N' | 'S' | 'E' | 'W')) => (&text[..text.len() - 1], Some(c)),
+            _ => (&text[..], None),
+        };
+        let (negative, text) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        if negative && hemisphere.is_some() {
+            return Err(invalid("has both a sign and a hemisphere"));
+        }
+
+        // degrees, minutes and seconds, in that order
+        let mut parts = [None; 3];
+        let mut rest = text;
+        let mut next = 0;
+        while !rest.is_empty() {
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .ok_or_else(|| invalid("has a number with no unit"))?;
+            let number: f64 = rest[..end]
+                .parse()
+                .map_err(|_| invalid("has a malformed number"))?;
+            let marker = rest[end..].chars().next().unwrap();
+            let part = match marker {
+                '°' | 'd' => 0,
+                '\'' | '′' => 1,
+                '"' | '″' => 2,
+                _ => return Err(invalid("has an unknown unit")),
+            };
+            if part < next {
+                return Err(invalid("has its parts out of order"));
+            }
+            parts[part] = Some(number);
+            next = part + 1;
+            rest = &rest[end + marker.len_utf8()..];
+        }
+
+        let [Some(degrees), minutes, seconds] = parts else {
+            return Err(invalid("has no degrees"));
+        };
+        let (minutes, seconds) = (minutes.unwrap_or(0.0), seconds.unwrap_or(0.0));
+        if minutes >= 60.0 || seconds >= 60.0 {
+            return Err(invalid("has 60 or more minutes or seconds"));
+        }
+
+        let magnitude = degrees + minutes / 60.0 + seconds / 3600.0;
+        let limit = match hemisphere {
+            Some('N' | 'S') => 90.0,
+            Some(_) => 180.0,
+            None => f64::INFINITY,
+        };
+        if magnitude > limit {
+            return Err(invalid("is out of range for its hemisphere"));
+        }
+
+        Ok(match (negative, hemisphere) {
+            (true, _) | (_, Some('S' | 'W')) => Dms(-magnitude),
+            _ => Dms(magnitude),
+        })
+    }
+}
+
+impl From<Radians> for Dms {
+    fn from(rad: Radians) -> Dms {
+        Dms(rad.0.to_degrees())
+    }
+}
+
+impl From<Dms> for Radians {
+    fn from(dms: Dms) -> Radians {
+        Radians(dms.0.to_radians())
+    }
+}
+
+impl From<Dms> for Degrees {
+    fn from(dms: Dms) -> Degrees {
+        Degrees(dms.0)
+    }
+}
+
+impl From<Degrees> for Dms {
+    fn from(deg: Degrees) -> Dms {
+        Dms(deg.0)
+    }
+}
+
+impl Angle for Dms {
+    const FULL_TURN: f64 = 360.0;
+
+    fn value(self) -> f64 {
+        self.0
+    }
+
+    fn from_value(value: f64) -> Self {
+        Dms(value)
+    }
+}
+
+angle_arithmetic!(Dms);
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::MathError,
+        week6::angle::{Angle, Degrees, Radians},
+    };
+
+    use super::Dms;
+
+    #[test]
+    fn parts() {
+        let dms = Dms::new(40, 26, 46.0);
+
+        assert_approx_eq!(Degrees::from(dms), Degrees(40.446111111111));
+        assert_eq!(dms.parts(), (false, 40, 26, 46.0));
+        assert_eq!((-dms).parts(), (true, 40, 26, 46.0));
+        // 59.9999 seconds carries over into the next minute
+        assert_eq!(Dms::new(10, 59, 59.9999).parts(), (false, 11, 0, 0.0));
+    }
+
+    #[test]
+    fn parsing() {
+        assert_approx_eq!(
+            "40°26'46\"N".parse::<Dms>().unwrap(),
+            Dms::new(40, 26, 46.0)
+        );
+        assert_approx_eq!(
+            "79° 58′ 56″ W".parse::<Dms>().unwrap(),
+            -Dms::new(79, 58, 56.0)
+        );
+        assert_approx_eq!("-12.5°".parse::<Dms>().unwrap(), Dms(-12.5));
+        assert_approx_eq!("33d 30'".parse::<Dms>().unwrap(), Dms(33.5));
+
+        for invalid in ["", "40", "26'40°", "40°61'", "91°N", "-40°S", "40°26'46\"Q"] {
+            assert!(
+                matches!(invalid.parse::<Dms>(), Err(MathError::Parse(_))),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn formatting() {
+        let latitude: Dms = "40°26'46\"N".parse().unwrap();
+        let longitude: Dms = "79°58'56\"W".parse().unwrap();
+
+        assert_eq!(latitude.to_latitude(), "40°26'46\"N");
+        assert_eq!(longitude.to_longitude(), "79°58'56\"W");
+        assert_eq!(longitude.to_string(), "-79°58'56\"");
+        assert_eq!(Dms::new(1, 2, 3.25).to_string(), "1°2'3.25\"");
+    }
+
+    #[test]
+    fn angle() {
+        let dms = Dms::new(90, 0, 0.0);
+
+        assert_approx_eq!(Radians::from(dms).sin(), 1.0);
+        assert!(dms.is_equivalent(Degrees(-270.0)));
+        assert_approx_eq!(Dms(-90.0).to_unit_circle(), Dms(270.0));
+        assert_approx_eq!(dms + Dms::new(0, 30, 0.0), Dms(90.5));
+    }
+}
@@ -1,6 +1,9 @@
 use std::ops::{Mul, Shl, Shr};
 
-use crate::week5::{scalar::Scalar, vector::Vector};
+use crate::{
+    error::MathError,
+    week5::{scalar::Scalar, vector::Vector},
+};
 
 use super::angle::Radians;
 
@@ -15,16 +18,43 @@ impl Vector {
         )
     }
 
+    pub fn checked_dot(&self, other: &Self) -> Result<Scalar, MathError> {
+        if self.0.len() != other.0.len() {
+            return Err(MathError::DimensionMismatch {
+                left: self.0.len(),
+                right: other.0.len(),
+            });
+        }
+        Ok(self.dotted(other))
+    }
+
     pub fn projected_on(&self, base: &Self) -> Self {
         base.multiplied(self.dotted(base) / base.magnitude_squared())
     }
 
+    pub fn checked_projected_on(&self, base: &Self) -> Result<Self, MathError> {
+        let dot = self.checked_dot(base)?;
+        let base_squared = base.magnitude_squared();
+        if base_squared.0 == 0.0 {
+            return Err(MathError::ZeroVector);
+        }
+        Ok(base.multiplied(dot / base_squared))
+    }
+
+    // NaN if either vector is zero, see try_angle_between
     pub fn angle_between(&self, other: &Vector) -> Radians {
-        Radians(
-            (self.dotted(other) / (self.magnitude() * other.magnitude()))
-                .0
-                .acos(),
-        )
+        let cos = self.dotted(other) / (self.magnitude() * other.magnitude());
+        // rounding can push parallel vectors just past +-1, out of acos' domain
+        Radians(cos.0.clamp(-1.0, 1.0).acos())
+    }
+
+    pub fn try_angle_between(&self, other: &Vector) -> Result<Radians, MathError> {
+        let dot = self.checked_dot(other)?;
+        let lengths = self.magnitude() * other.magnitude();
+        if lengths.0 == 0.0 {
+            return Err(MathError::ZeroVector);
+        }
+        Ok(Radians((dot / lengths).0.clamp(-1.0, 1.0).acos()))
     }
 }
 
@@ -33,7 +63,7 @@ impl Mul<Vector> for Vector {
     type Output = Scalar;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        self.dotted(&rhs)
+        self.checked_dot(&rhs).unwrap()
     }
 }
 
@@ -43,7 +73,7 @@ impl Shl<Vector> for Vector {
     type Output = Vector;
 
     fn shl(self, rhs: Vector) -> Self::Output {
-        rhs.projected_on(&self)
+        rhs.checked_projected_on(&self).unwrap()
     }
 }
 
@@ -51,6 +81,44 @@ impl Shr<Vector> for Vector {
     type Output = Vector;
 
     fn shr(self, rhs: Vector) -> Self::Output {
-        self.projected_on(&rhs)
+        self.checked_projected_on(&rhs).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::PI;
+
+    use crate::{error::MathError, week5::vector::Vector, week6::angle::Radians};
+
+    #[test]
+    fn angles() {
+        let v = Vector(vec![0.1, 1.1, 1.2]);
+
+        // v . 3v / (|v| |3v|) rounds to just over 1
+        assert_eq!(v.angle_between(&(v.clone() * 3.0)), Radians(0.0));
+        assert_eq!(v.try_angle_between(&(v.clone() * 3.0)), Ok(Radians(0.0)));
+        assert_eq!(v.try_angle_between(&-(v.clone() * 3.0)), Ok(Radians(PI)));
+
+        assert_eq!(
+            v.try_angle_between(&Vector(vec![0.0, 0.0, 0.0])),
+            Err(MathError::ZeroVector)
+        );
+        assert_eq!(
+            v.try_angle_between(&Vector(vec![1.0, 0.0])),
+            Err(MathError::DimensionMismatch { left: 3, right: 2 })
+        );
+    }
+
+    #[test]
+    fn units() {
+        assert_eq!(
+            Vector(vec![3.0, 0.0, 4.0]).try_unit(),
+            Ok(Vector(vec![0.6, 0.0, 0.8]))
+        );
+        assert_eq!(
+            Vector(vec![0.0, 0.0]).try_into_unit(),
+            Err(MathError::ZeroVector)
+        );
     }
 }
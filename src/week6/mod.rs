@@ -1,5 +1,8 @@
 pub mod angle;
+pub mod direction;
+pub mod dms;
 pub mod dot;
+pub mod norm;
 
 #[cfg(test)]
 mod tests {
@@ -18,7 +21,7 @@ mod tests {
         let u = Vector(vec![-2.0, 1.0, -2.0]);
         let v = Vector(vec![1.0, 2.0, 1.0]);
 
-        assert_eq!((u + (v * 3.0)).magnitude(), Scalar(51.0_f64.sqrt()));
+        assert_approx_eq!((u + (v * 3.0)).magnitude(), Scalar(51.0_f64.sqrt()));
     }
 
     #[test]
@@ -26,7 +29,7 @@ mod tests {
         let v = Vector(vec![2.0, -1.0, 3.0]);
         let u = v.unit_in_direction();
 
-        assert_eq!(v / 14.0_f64.sqrt(), u);
+        assert_approx_eq!(v / 14.0_f64.sqrt(), u);
     }
 
     #[test]
@@ -0,0 +1,243 @@
+use crate::{
+    error::MathError,
+    linalg::matrix::Matrix,
+    week5::{scalar::Scalar, vector::Vector},
+};
+
+use super::angle::Radians;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Norm {
+    // sum of |x|, taxicab length
+    L1,
+    // sqrt of the sum of x^2, the same as magnitude
+    L2,
+    // largest |x|
+    LInfinity,
+    // p-th root of the sum of |x|^p. p has to be at least 1, anything smaller isn't a norm, and
+    // infinity gives the same as LInfinity
+    P(f64),
+}
+
+impl Vector {
+    pub fn norm(&self, norm: Norm) -> Result<Scalar, MathError> {
+        let absolute = self.0.iter().map(|x| x.abs());
+        let largest = absolute.clone().fold(0.0, f64::max);
+        Ok(Scalar(match norm {
+            Norm::L1 => absolute.sum(),
+            Norm::L2 => self.magnitude().0,
+            Norm::LInfinity => largest,
+            // the limit as p grows
+            Norm::P(p) if p == f64::INFINITY => largest,
+            Norm::P(p) if p.is_nan() || p < 1.0 => {
+                return Err(MathError::InvalidNormExponent { p })
+            }
+            Norm::P(_) if largest == 0.0 => 0.0,
+            // dividing by the largest entry first keeps |x|^p from overflowing for large p
+            Norm::P(p) => {
+                largest
+                    * absolute
+                        .map(|x| (x / largest).powf(p))
+                        .sum::<f64>()
+                        .powf(1.0 / p)
+            }
+        }))
+    }
+
+    // scaled to length 1 under the given norm
+    pub fn normalized(&self, norm: Norm) -> Result<Vector, MathError> {
+        let length = self.norm(norm)?;
+        if length.0 == 0.0 {
+            return Err(MathError::ZeroVector);
+        }
+        Ok(self.clone() / length)
+    }
+
+    // the norm of the difference
+    pub fn distance_to(&self, other: &Vector, norm: Norm) -> Result<Scalar, MathError> {
+        self.subtracted(other)?.norm(norm)
+    }
+
+    pub fn euclidean_distance(&self, other: &Vector) -> Result<Scalar, MathError> {
+        self.distance_to(other, Norm::L2)
+    }
+
+    pub fn manhattan_distance(&self, other: &Vector) -> Result<Scalar, MathError> {
+        self.distance_to(other, Norm::L1)
+    }
+
+    pub fn chebyshev_distance(&self, other: &Vector) -> Result<Scalar, MathError> {
+        self.distance_to(other, Norm::LInfinity)
+    }
+
+    // 1 - cos(angle between), 0 for the same direction up to 2 for opposite ones
+    pub fn cosine_distance(&self, other: &Vector) -> Result<Scalar, MathError> {
+        let lengths = self.magnitude() * other.magnitude();
+        if lengths.0 == 0.0 {
+            return Err(MathError::ZeroVector);
+        }
+        Ok(1.0 - self.checked_dot(other)? / lengths)
+    }
+
+    // u^T W v, where W has to be symmetric positive definite for this to be an inner product
+    pub fn weighted_dot(&self, other: &Vector, weights: &Matrix) -> Result<Scalar, MathError> {
+        weights.cholesky()?;
+        self.unchecked_weighted_dot(other, weights)
+    }
+
+    pub fn weighted_magnitude(&self, weights: &Matrix) -> Result<Scalar, MathError> {
+        weights.cholesky()?;
+        self.unchecked_weighted_magnitude(weights)
+    }
+
+    // angle_between, measured with the weighted inner product
+    pub fn weighted_angle_between(
+        &self,
+        other: &Vector,
+        weights: &Matrix,
+    ) -> Result<Radians, MathError> {
+        weights.cholesky()?;
+        let lengths = self.unchecked_weighted_magnitude(weights)?
+            * other.unchecked_weighted_magnitude(weights)?;
+        if lengths.0 == 0.0 {
+            return Err(MathError::ZeroVector);
+        }
+        let cos = self.unchecked_weighted_dot(other, weights)? / lengths;
+        Ok(Radians(cos.0.clamp(-1.0, 1.0).acos()))
+    }
+
+    // the weights are only checked once by the public versions, a factorization per product
+    // would be most of the work
+    fn unchecked_weighted_dot(
+        &self,
+        other: &Vector,
+        weights: &Matrix,
+    ) -> Result<Scalar, MathError> {
+        self.checked_dot(&weights.apply(other)?)
+    }
+
+    fn unchecked_weighted_magnitude(&self, weights: &Matrix) -> Result<Scalar, MathError> {
+        Ok(Scalar(self.unchecked_weighted_dot(self, weights)?.0.sqrt()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::{
+        approx::Tolerance,
+        error::MathError,
+        linalg::matrix::Matrix,
+        week5::{scalar::Scalar, vector::Vector},
+        week6::angle::Radians,
+    };
+
+    use super::Norm;
+
+    #[test]
+    fn norms() {
+        let v = Vector(vec![3.0, -4.0, 0.0]);
+
+        assert_eq!(v.norm(Norm::L1), Ok(Scalar(7.0)));
+        assert_eq!(v.norm(Norm::L2), Ok(Scalar(5.0)));
+        assert_eq!(v.norm(Norm::LInfinity), Ok(Scalar(4.0)));
+        assert_approx_eq!(v.norm(Norm::P(2.0)).unwrap(), Scalar(5.0));
+        assert_approx_eq!(v.norm(Norm::P(3.0)).unwrap(), Scalar(91.0_f64.cbrt()));
+        // large p approaches the largest entry
+        assert_approx_eq!(
+            v.norm(Norm::P(100.0)).unwrap().0,
+            4.0,
+            Tolerance::absolute(1e-2)
+        );
+        assert_eq!(v.norm(Norm::P(f64::INFINITY)), Ok(Scalar(4.0)));
+        // 10^400 doesn't fit in an f64
+        assert_approx_eq!(
+            Vector(vec![10.0, 1.0]).norm(Norm::P(400.0)).unwrap(),
+            Scalar(10.0)
+        );
+        assert_eq!(Vector(vec![0.0, 0.0]).norm(Norm::P(3.0)), Ok(Scalar(0.0)));
+        assert_eq!(
+            v.norm(Norm::P(0.5)),
+            Err(MathError::InvalidNormExponent { p: 0.5 })
+        );
+        assert!(v.norm(Norm::P(f64::NAN)).is_err());
+
+        assert_approx_eq!(
+            v.normalized(Norm::L1).unwrap(),
+            Vector(vec![3.0 / 7.0, -4.0 / 7.0, 0.0])
+        );
+        assert_approx_eq!(
+            v.normalized(Norm::LInfinity).unwrap(),
+            Vector(vec![0.75, -1.0, 0.0])
+        );
+        assert_eq!(
+            Vector(vec![0.0, 0.0]).normalized(Norm::L2),
+            Err(MathError::ZeroVector)
+        );
+    }
+
+    #[test]
+    fn distances() {
+        let u = Vector(vec![1.0, 2.0]);
+        let v = Vector(vec![4.0, -2.0]);
+
+        assert_eq!(u.euclidean_distance(&v), Ok(Scalar(5.0)));
+        assert_eq!(u.manhattan_distance(&v), Ok(Scalar(7.0)));
+        assert_eq!(u.chebyshev_distance(&v), Ok(Scalar(4.0)));
+        assert_approx_eq!(
+            u.cosine_distance(&Vector(vec![-2.0, 1.0])).unwrap(),
+            Scalar(1.0)
+        );
+        assert_approx_eq!(u.cosine_distance(&(u.clone() * 3.0)).unwrap(), Scalar(0.0));
+
+        assert_eq!(
+            u.manhattan_distance(&Vector(vec![1.0])),
+            Err(MathError::DimensionMismatch { left: 2, right: 1 })
+        );
+        assert_eq!(
+            u.cosine_distance(&Vector(vec![0.0, 0.0])),
+            Err(MathError::ZeroVector)
+        );
+    }
+
+    #[test]
+    fn weighted() {
+        let weights = Matrix::new(2, 2, vec![2.0, 0.0, 0.0, 1.0]).unwrap();
+        let u = Vector(vec![1.0, 1.0]);
+        let v = Vector(vec![1.0, -2.0]);
+
+        // orthogonal under the weights even though u . v = -1
+        assert_eq!(u.weighted_dot(&v, &weights), Ok(Scalar(0.0)));
+        assert_approx_eq!(
+            u.weighted_angle_between(&v, &weights).unwrap(),
+            Radians(FRAC_PI_2)
+        );
+        assert_approx_eq!(
+            u.weighted_magnitude(&weights).unwrap(),
+            Scalar(3.0_f64.sqrt())
+        );
+
+        // the identity gives back the usual angle
+        assert_approx_eq!(
+            u.weighted_angle_between(&v, &Matrix::identity(2)).unwrap(),
+            u.angle_between(&v)
+        );
+
+        let indefinite = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, -1.0]).unwrap();
+        assert_eq!(
+            u.weighted_dot(&v, &indefinite),
+            Err(MathError::NotPositiveDefinite)
+        );
+        let lopsided = Matrix::new(2, 2, vec![1.0, 1.0, 0.0, 1.0]).unwrap();
+        assert_eq!(u.weighted_dot(&v, &lopsided), Err(MathError::NotSymmetric));
+        assert_eq!(
+            u.weighted_magnitude(&indefinite),
+            Err(MathError::NotPositiveDefinite)
+        );
+        assert_eq!(
+            u.weighted_angle_between(&v, &lopsided),
+            Err(MathError::NotSymmetric)
+        );
+    }
+}
@@ -0,0 +1,294 @@
+use crate::{
+    approx::{ApproxEq, Tolerance},
+    error::MathError,
+    week5::vector::Vector,
+    week6::angle::{Angle, Radians},
+    week7::point::{Point2, Point3},
+};
+
+// converting from a point always gives r >= 0 and theta in (-pi, pi], use normalized() to get
+// a hand written coordinate into the same form
+
+// x = r cos(theta), y = r sin(theta)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Polar {
+    pub r: f64,
+    pub theta: Radians,
+}
+
+// polar coordinates in the xy plane, z is left as is
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cylindrical {
+    pub r: f64,
+    pub theta: Radians,
+    pub z: f64,
+}
+
+// rho is the distance from the origin, theta the same as in cylindrical and phi the angle down
+// from the positive z axis, in [0, pi]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spherical {
+    pub rho: f64,
+    pub theta: Radians,
+    pub phi: Radians,
+}
+
+impl Polar {
+    pub fn new(r: f64, theta: impl Angle) -> Polar {
+        Polar {
+            r,
+            theta: theta.into(),
+        }
+    }
+
+    pub fn normalized(self) -> Polar {
+        Point2::from(self).into()
+    }
+}
+
+impl Cylindrical {
+    pub fn new(r: f64, theta: impl Angle, z: f64) -> Cylindrical {
+        Cylindrical {
+            r,
+            theta: theta.into(),
+            z,
+        }
+    }
+
+    pub fn normalized(self) -> Cylindrical {
+        Point3::from(self).into()
+    }
+}
+
+impl Spherical {
+    pub fn new(rho: f64, theta: impl Angle, phi: impl Angle) -> Spherical {
+        Spherical {
+            rho,
+            theta: theta.into(),
+            phi: phi.into(),
+        }
+    }
+
+    pub fn normalized(self) -> Spherical {
+        Point3::from(self).into()
+    }
+}
+
+// ========== polar ==========
+
+impl From<Polar> for Point2 {
+    fn from(p: Polar) -> Point2 {
+        Point2(p.r * p.theta.cos(), p.r * p.theta.sin())
+    }
+}
+
+impl From<Point2> for Polar {
+    fn from(Point2(x, y): Point2) -> Polar {
+        Polar {
+            r: x.hypot(y),
+            theta: Radians(y.atan2(x)),
+        }
+    }
+}
+
+impl From<Polar> for Vector {
+    fn from(p: Polar) -> Vector {
+        Point2::from(p).to_vector()
+    }
+}
+
+impl TryFrom<&Vector> for Polar {
+    type Error = MathError;
+
+    fn try_from(v: &Vector) -> Result<Polar, MathError> {
+        Ok(Point2::from_vector(v)?.into())
+    }
+}
+
+// ========== cylindrical ==========
+
+impl From<Cylindrical> for Point3 {
+    fn from(c: Cylindrical) -> Point3 {
+        let Point2(x, y) = Polar::new(c.r, c.theta).into();
+        Point3(x, y, c.z)
+    }
+}
+
+impl From<Point3> for Cylindrical {
+    fn from(Point3(x, y, z): Point3) -> Cylindrical {
+        let Polar { r, theta } = Point2(x, y).into();
+        Cylindrical { r, theta, z }
+    }
+}
+
+impl From<Cylindrical> for Vector {
+    fn from(c: Cylindrical) -> Vector {
+        Point3::from(c).to_vector()
+    }
+}
+
+impl TryFrom<&Vector> for Cylindrical {
+    type Error = MathError;
+
+    fn try_from(v: &Vector) -> Result<Cylindrical, MathError> {
+        Ok(Point3::from_vector(v)?.into())
+    }
+}
+
+// ========== spherical ==========
+
+impl From<Spherical> for Point3 {
+    fn from(s: Spherical) -> Point3 {
+        let r = s.rho * s.phi.sin();
+        Point3(r * s.theta.cos(), r * s.theta.sin(), s.rho * s.phi.cos())
+    }
+}
+
+impl From<Point3> for Spherical {
+    fn from(Point3(x, y, z): Point3) -> Spherical {
+        let r = x.hypot(y);
+        Spherical {
+            rho: r.hypot(z),
+            theta: Radians(y.atan2(x)),
+            // atan2 instead of acos(z / rho), so the origin gets phi = 0 instead of NaN
+            phi: Radians(r.atan2(z)),
+        }
+    }
+}
+
+impl From<Spherical> for Vector {
+    fn from(s: Spherical) -> Vector {
+        Point3::from(s).to_vector()
+    }
+}
+
+impl TryFrom<&Vector> for Spherical {
+    type Error = MathError;
+
+    fn try_from(v: &Vector) -> Result<Spherical, MathError> {
+        Ok(Point3::from_vector(v)?.into())
+    }
+}
+
+impl From<Cylindrical> for Spherical {
+    fn from(c: Cylindrical) -> Spherical {
+        Point3::from(c).into()
+    }
+}
+
+impl From<Spherical> for Cylindrical {
+    fn from(s: Spherical) -> Cylindrical {
+        Point3::from(s).into()
+    }
+}
+
+// component wise, so only normalized coordinates compare equal to the same point
+impl ApproxEq for Polar {
+    fn approx_eq_with(&self, other: &Polar, tolerance: Tolerance) -> bool {
+        self.r.approx_eq_with(&other.r, tolerance)
+            && self.theta.approx_eq_with(&other.theta, tolerance)
+    }
+}
+
+impl ApproxEq for Cylindrical {
+    fn approx_eq_with(&self, other: &Cylindrical, tolerance: Tolerance) -> bool {
+        self.r.approx_eq_with(&other.r, tolerance)
+            && self.theta.approx_eq_with(&other.theta, tolerance)
+            && self.z.approx_eq_with(&other.z, tolerance)
+    }
+}
+
+impl ApproxEq for Spherical {
+    fn approx_eq_with(&self, other: &Spherical, tolerance: Tolerance) -> bool {
+        self.rho.approx_eq_with(&other.rho, tolerance)
+            && self.theta.approx_eq_with(&other.theta, tolerance)
+            && self.phi.approx_eq_with(&other.phi, tolerance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, PI};
+
+    use crate::{
+        error::MathError,
+        week5::vector::Vector,
+        week6::angle::{Degrees, Radians},
+        week7::point::{Point2, Point3},
+    };
+
+    use super::{Cylindrical, Polar, Spherical};
+
+    #[test]
+    fn polar() {
+        // stewart 10.3 example 1 and 2
+        assert_approx_eq!(
+            Point2::from(Polar::new(2.0, Radians(FRAC_PI_3))),
+            Point2(1.0, 3.0_f64.sqrt())
+        );
+        assert_approx_eq!(
+            Polar::from(Point2(1.0, -1.0)),
+            Polar::new(2.0_f64.sqrt(), Radians(-FRAC_PI_4))
+        );
+
+        // a negative r points the other way
+        assert_approx_eq!(
+            Polar::new(-1.0, Degrees(90.0)).normalized(),
+            Polar::new(1.0, Degrees(-90.0))
+        );
+        assert_approx_eq!(
+            Polar::new(3.0, Degrees(540.0)).normalized(),
+            Polar::new(3.0, Radians(PI))
+        );
+    }
+
+    #[test]
+    fn cylindrical() {
+        // stewart 15.7 example 1
+        assert_approx_eq!(
+            Point3::from(Cylindrical::new(2.0, Radians(2.0 * FRAC_PI_3), 1.0)),
+            Point3(-1.0, 3.0_f64.sqrt(), 1.0)
+        );
+        assert_approx_eq!(
+            Cylindrical::from(Point3(3.0, -3.0, -7.0)),
+            Cylindrical::new(3.0 * 2.0_f64.sqrt(), Radians(-FRAC_PI_4), -7.0)
+        );
+    }
+
+    #[test]
+    fn spherical() {
+        // stewart 15.8 example 2 and 3
+        let half = 1.5_f64.sqrt();
+        assert_approx_eq!(
+            Point3::from(Spherical::new(2.0, Radians(FRAC_PI_4), Radians(FRAC_PI_3))),
+            Point3(half, half, 1.0)
+        );
+        assert_approx_eq!(
+            Spherical::from(Point3(0.0, 2.0 * 3.0_f64.sqrt(), -2.0)),
+            Spherical::new(4.0, Radians(FRAC_PI_2), Radians(2.0 * FRAC_PI_3))
+        );
+
+        assert_approx_eq!(
+            Spherical::from(Point3::ZERO),
+            Spherical::new(0.0, Radians(0.0), Radians(0.0))
+        );
+        assert_approx_eq!(
+            Spherical::from(Cylindrical::new(1.0, Degrees(30.0), 1.0)),
+            Spherical::new(2.0_f64.sqrt(), Degrees(30.0), Degrees(45.0))
+        );
+    }
+
+    #[test]
+    fn vectors() {
+        let v = Vector(vec![1.0, 2.0, 2.0]);
+        let s = Spherical::try_from(&v).unwrap();
+
+        assert_approx_eq!(s.rho, 3.0);
+        assert_approx_eq!(Vector::from(s), v);
+        assert_approx_eq!(Vector::from(Cylindrical::try_from(&v).unwrap()), v);
+        assert_eq!(
+            Polar::try_from(&v),
+            Err(MathError::DimensionMismatch { left: 2, right: 3 })
+        );
+    }
+}
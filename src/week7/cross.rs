@@ -1,11 +1,11 @@
 use std::ops::BitXor;
 
-use crate::week5::vector::Vector;
+use crate::{error::MathError, week5::vector::Vector};
 
 impl Vector {
-    pub fn crossed(&self, other: &Self) -> Result<Self, ()> {
-        if self.0.len() != 3 || other.0.len() != 3 {
-            Err(())
+    pub fn crossed(&self, other: &Self) -> Result<Self, MathError> {
+        if let Some(found) = [self.0.len(), other.0.len()].into_iter().find(|&d| d != 3) {
+            Err(MathError::UnsupportedDimension { expected: 3, found })
         } else {
             let u = &self.0;
             let v = &other.0;
@@ -16,13 +16,17 @@ impl Vector {
             ]))
         }
     }
+
+    pub fn checked_cross(self, rhs: Self) -> Result<Self, MathError> {
+        self.crossed(&rhs)
+    }
 }
 
 impl BitXor<Vector> for Vector {
     type Output = Self;
 
     fn bitxor(self, rhs: Vector) -> Self::Output {
-        self.crossed(&rhs).unwrap()
+        self.checked_cross(rhs).unwrap()
     }
 }
 
@@ -44,9 +48,12 @@ impl BitXor<&Vector> for Vector {
 
 #[cfg(test)]
 mod tests {
-    use crate::week5::vector::{
-        unit::three_d::{i, j, k},
-        Vector,
+    use crate::{
+        error::MathError,
+        week5::vector::{
+            unit::three_d::{i, j, k},
+            Vector,
+        },
     };
 
     #[test]
@@ -64,6 +71,17 @@ mod tests {
         )
     }
 
+    #[test]
+    fn unsupported_dimension() {
+        assert_eq!(
+            Vector(vec![1.0, 2.0]).checked_cross(Vector(vec![1.0, 2.0, 3.0])),
+            Err(MathError::UnsupportedDimension {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
     #[test]
     fn question_4() {
         println!(
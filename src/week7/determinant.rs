@@ -2,9 +2,13 @@
 
 use std::ops::{Add, Mul, Sub};
 
-use crate::week5::{scalar::Scalar, vector};
+use crate::{
+    error::MathError,
+    linalg::matrix::Matrix,
+    week5::{scalar::Scalar, vector},
+};
 
-pub fn cross(vectors: Vec<vector::Vector>) -> Value {
+pub fn cross(vectors: Vec<vector::Vector>) -> Result<Value, MathError> {
     let mut matrix: Vec<Value> = vec![];
 
     matrix.append(&mut units(vectors.len() + 1));
@@ -36,20 +40,48 @@ pub enum Value {
     Scalar(Scalar),
 }
 
+impl Value {
+    pub fn checked_add(self, rhs: Value) -> Result<Value, MathError> {
+        match (self, rhs) {
+            (Value::Vector(inner), Value::Vector(rhs_inner)) => {
+                Ok(Value::Vector(inner.checked_add(rhs_inner)?))
+            }
+            (Value::Scalar(inner), Value::Scalar(rhs_inner)) => {
+                Ok(Value::Scalar(inner + rhs_inner))
+            }
+            _ => Err(MathError::MismatchedValues),
+        }
+    }
+
+    pub fn checked_sub(self, rhs: Value) -> Result<Value, MathError> {
+        match (self, rhs) {
+            (Value::Vector(inner), Value::Vector(rhs_inner)) => {
+                Ok(Value::Vector(inner.checked_sub(rhs_inner)?))
+            }
+            (Value::Scalar(inner), Value::Scalar(rhs_inner)) => {
+                Ok(Value::Scalar(inner - rhs_inner))
+            }
+            _ => Err(MathError::MismatchedValues),
+        }
+    }
+
+    pub fn checked_mul(self, rhs: Value) -> Result<Value, MathError> {
+        Ok(match (self, rhs) {
+            (Value::Vector(inner), Value::Vector(rhs_inner)) => {
+                Value::Scalar(inner.checked_dot(&rhs_inner)?)
+            }
+            (Value::Vector(inner), Value::Scalar(rhs_inner)) => Value::Vector(inner * rhs_inner),
+            (Value::Scalar(inner), Value::Scalar(rhs_inner)) => Value::Scalar(inner * rhs_inner),
+            (Value::Scalar(inner), Value::Vector(rhs_inner)) => Value::Vector(rhs_inner * inner),
+        })
+    }
+}
+
 impl Add<Value> for Value {
     type Output = Value;
 
     fn add(self, rhs: Value) -> Self::Output {
-        match self {
-            Value::Vector(inner) => match rhs {
-                Value::Vector(rhs_inner) => Value::Vector(inner + rhs_inner),
-                Value::Scalar(_) => panic!(),
-            },
-            Value::Scalar(inner) => match rhs {
-                Value::Scalar(rhs_inner) => Value::Scalar(inner + rhs_inner),
-                Value::Vector(_) => panic!(),
-            },
-        }
+        self.checked_add(rhs).unwrap()
     }
 }
 
@@ -57,16 +89,7 @@ impl Sub<Value> for Value {
     type Output = Value;
 
     fn sub(self, rhs: Value) -> Self::Output {
-        match self {
-            Value::Vector(inner) => match rhs {
-                Value::Vector(rhs_inner) => Value::Vector(inner - rhs_inner),
-                Value::Scalar(_) => panic!(),
-            },
-            Value::Scalar(inner) => match rhs {
-                Value::Scalar(rhs_inner) => Value::Scalar(inner - rhs_inner),
-                Value::Vector(_) => panic!(),
-            },
-        }
+        self.checked_sub(rhs).unwrap()
     }
 }
 
@@ -74,55 +97,76 @@ impl Mul<Value> for Value {
     type Output = Value;
 
     fn mul(self, rhs: Value) -> Self::Output {
-        match self {
-            Value::Vector(inner) => match rhs {
-                Value::Vector(rhs_inner) => Value::Scalar(inner * rhs_inner),
-                Value::Scalar(rhs_inner) => Value::Vector(inner * rhs_inner),
-            },
-            Value::Scalar(inner) => match rhs {
-                Value::Scalar(rhs_inner) => Value::Scalar(inner * rhs_inner),
-                Value::Vector(rhs_inner) => Value::Vector(rhs_inner * inner),
-            },
-        }
+        self.checked_mul(rhs).unwrap()
     }
 }
 
 pub type SquareMatrix = Vec<Value>;
 
-pub fn determinant(matrix: SquareMatrix) -> Value {
-    println!("{:?}", matrix);
-
-    if matrix.len() == 1 {
-        matrix.get(0).unwrap().clone()
-    } else {
-        let size = (matrix.len() as f64).sqrt() as usize;
-
-        (0..size)
-            .map(|col| {
-                determinant(
-                    matrix
-                        .clone()
-                        .into_iter()
-                        .enumerate()
-                        .filter(|(i, _)| *i >= size && i % size != col)
-                        .map(|(_, v)| v)
-                        .collect(),
-                ) * (matrix.get(col).unwrap().clone())
-            })
-            .enumerate()
-            .reduce(|(_, acc), (i, v)| (0, if let 0 = i % 2 { acc + v } else { acc - v }))
-            .map(|(_, v)| v)
-            .unwrap()
+pub fn determinant(matrix: SquareMatrix) -> Result<Value, MathError> {
+    let size = (matrix.len() as f64).sqrt() as usize;
+    if size * size != matrix.len() {
+        return Err(MathError::NotASquareLength {
+            length: matrix.len(),
+        });
     }
+
+    // purely numeric matrices go through LU decomposition,
+    // anything holding vectors (like the unit vector row of `cross`) needs cofactor expansion
+    let numeric: Option<Vec<f64>> = matrix
+        .iter()
+        .map(|v| match v {
+            Value::Scalar(Scalar(x)) => Some(*x),
+            Value::Vector(_) => None,
+        })
+        .collect();
+
+    match numeric {
+        Some(entries) => Ok(Value::Scalar(
+            Matrix::new(size, size, entries)?.determinant()?,
+        )),
+        None => cofactor_expansion(&matrix, size),
+    }
+}
+
+// expands along the first row
+fn cofactor_expansion(matrix: &[Value], size: usize) -> Result<Value, MathError> {
+    if size == 1 {
+        return Ok(matrix[0].clone());
+    }
+
+    (0..size)
+        .map(|col| {
+            let minor = matrix[size..]
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| i % size != col)
+                .map(|(_, v)| v.clone())
+                .collect();
+            determinant(minor)?.checked_mul(matrix[col].clone())
+        })
+        .enumerate()
+        .try_fold(None, |acc: Option<Value>, (i, v)| {
+            let v = v?;
+            Ok(Some(match acc {
+                None => v,
+                Some(acc) if i % 2 == 0 => acc.checked_add(v)?,
+                Some(acc) => acc.checked_sub(v)?,
+            }))
+        })
+        .map(|v| v.unwrap())
 }
 
 #[cfg(test)]
 mod test {
-    use crate::week5::{
-        scalar::Scalar,
-        vector::{
-            unit::three_d::{i, j, k},
-            Vector,
+    use crate::{
+        error::MathError,
+        week5::{
+            scalar::Scalar,
+            vector::{
+                unit::three_d::{i, j, k},
+                Vector,
+            },
         },
     };
 
@@ -132,15 +176,40 @@ mod test {
     fn basecase() {
         assert_eq!(
             determinant(vec![Value::Scalar(Scalar(1.))]),
-            Value::Scalar(Scalar(1.))
+            Ok(Value::Scalar(Scalar(1.)))
         )
     }
 
+    #[test]
+    fn numeric() {
+        assert_eq!(
+            determinant(
+                [3.0, 8.0, 4.0, 6.0]
+                    .into_iter()
+                    .map(|x| Value::Scalar(Scalar(x)))
+                    .collect()
+            ),
+            Ok(Value::Scalar(Scalar(-14.0)))
+        );
+    }
+
+    #[test]
+    fn non_square() {
+        assert_eq!(
+            determinant(vec![Value::Scalar(Scalar(1.)), Value::Scalar(Scalar(2.))]),
+            Err(MathError::NotASquareLength { length: 2 })
+        );
+        assert_eq!(
+            determinant(vec![Value::Scalar(Scalar(1.)); 5]),
+            Err(MathError::NotASquareLength { length: 5 })
+        );
+    }
+
     #[test]
     fn units() {
-        assert_eq!(cross(vec![i(), j()]), Value::Vector(k()));
-        assert_eq!(cross(vec![j(), k()]), Value::Vector(i()));
-        assert_eq!(cross(vec![k(), i()]), Value::Vector(j()));
+        assert_eq!(cross(vec![i(), j()]), Ok(Value::Vector(k())));
+        assert_eq!(cross(vec![j(), k()]), Ok(Value::Vector(i())));
+        assert_eq!(cross(vec![k(), i()]), Ok(Value::Vector(j())));
     }
 
     #[test]
@@ -150,7 +219,9 @@ mod test {
                 Vector(vec![2.0, 1.0, 3.0]),
                 Vector(vec![-1.0, 2.0, 2.0])
             ]),
-            Value::Vector(Vector(vec![2.0, 1.0, 3.0]) ^ Vector(vec![-1.0, 2.0, 2.0]))
+            Ok(Value::Vector(
+                Vector(vec![2.0, 1.0, 3.0]) ^ Vector(vec![-1.0, 2.0, 2.0])
+            ))
         )
     }
 }
@@ -0,0 +1,246 @@
+use crate::week5::{scalar::Scalar as S, vector::Vector};
+use crate::week7::{
+    intersection::{Intersect, Intersection3},
+    line::Line3,
+    plane::Plane3,
+    point::Point3,
+};
+
+pub trait Distance<Rhs = Self> {
+    // a point on self and a point on other that are as close as possible,
+    // when there are many such pairs (parallel lines, say) any one of them
+    fn closest_points(&self, other: &Rhs) -> (Point3, Point3);
+
+    fn distance(&self, other: &Rhs) -> S {
+        let (from, to) = self.closest_points(other);
+        (to - from).magnitude()
+    }
+}
+
+// the orthogonal projection onto a line or plane, and the mirror image across it
+pub trait Project<Onto> {
+    fn project_onto(&self, onto: &Onto) -> Self;
+    fn reflect_across(&self, across: &Onto) -> Self;
+}
+
+impl Project<Plane3> for Vector {
+    fn project_onto(&self, plane: &Plane3) -> Vector {
+        self.clone() - self.projected_on(&plane.normal)
+    }
+
+    fn reflect_across(&self, plane: &Plane3) -> Vector {
+        self.clone() - self.projected_on(&plane.normal) * 2.0
+    }
+}
+
+impl Project<Line3> for Vector {
+    fn project_onto(&self, line: &Line3) -> Vector {
+        self.projected_on(&line.direction)
+    }
+
+    fn reflect_across(&self, line: &Line3) -> Vector {
+        self.projected_on(&line.direction) * 2.0 - self.clone()
+    }
+}
+
+impl Project<Plane3> for Point3 {
+    fn project_onto(&self, plane: &Plane3) -> Point3 {
+        // move against the part of the offset from the plane that is along its normal
+        *self + -(self - &plane.point).projected_on(&plane.normal)
+    }
+
+    fn reflect_across(&self, plane: &Plane3) -> Point3 {
+        *self + (self - &plane.point).projected_on(&plane.normal) * -2.0
+    }
+}
+
+impl Project<Line3> for Point3 {
+    fn project_onto(&self, line: &Line3) -> Point3 {
+        line.closest_point_to(self)
+    }
+
+    fn reflect_across(&self, line: &Line3) -> Point3 {
+        let projection = self.project_onto(line);
+        projection + (projection - self)
+    }
+}
+
+impl Distance for Point3 {
+    fn closest_points(&self, other: &Point3) -> (Point3, Point3) {
+        (*self, *other)
+    }
+}
+
+impl Distance<Line3> for Point3 {
+    fn closest_points(&self, line: &Line3) -> (Point3, Point3) {
+        (*self, self.project_onto(line))
+    }
+}
+
+impl Distance<Plane3> for Point3 {
+    fn closest_points(&self, plane: &Plane3) -> (Point3, Point3) {
+        (*self, self.project_onto(plane))
+    }
+}
+
+impl Distance for Line3 {
+    fn closest_points(&self, other: &Line3) -> (Point3, Point3) {
+        if self.is_parallel(other) {
+            return (self.point, self.point.project_onto(other));
+        }
+
+        // the segment between the two points is along the common normal n,
+        // crossing p1 + s d1 - p2 - t d2 with d2 or d1 leaves only s or t
+        let normal = &self.direction ^ &other.direction;
+        let offset = other.point - self.point;
+        let s = (&offset ^ &other.direction).dotted(&normal) / normal.magnitude_squared();
+        let t = (&offset ^ &self.direction).dotted(&normal) / normal.magnitude_squared();
+        (self.point_at(s.0), other.point_at(t.0))
+    }
+}
+
+impl Distance<Point3> for Line3 {
+    fn closest_points(&self, point: &Point3) -> (Point3, Point3) {
+        let (on_point, on_line) = point.closest_points(self);
+        (on_line, on_point)
+    }
+}
+
+impl Distance<Plane3> for Line3 {
+    fn closest_points(&self, plane: &Plane3) -> (Point3, Point3) {
+        match self.intersect(plane) {
+            Intersection3::Point(point) => (point, point),
+            // every point of a parallel line is equally far away
+            _ => (self.point, self.point.project_onto(plane)),
+        }
+    }
+}
+
+impl Distance<Point3> for Plane3 {
+    fn closest_points(&self, point: &Point3) -> (Point3, Point3) {
+        let (on_point, on_plane) = point.closest_points(self);
+        (on_plane, on_point)
+    }
+}
+
+impl Distance<Line3> for Plane3 {
+    fn closest_points(&self, line: &Line3) -> (Point3, Point3) {
+        let (on_line, on_plane) = line.closest_points(self);
+        (on_plane, on_line)
+    }
+}
+
+impl Distance for Plane3 {
+    fn closest_points(&self, other: &Plane3) -> (Point3, Point3) {
+        match self.intersect(other) {
+            Intersection3::Line(line) => (line.point, line.point),
+            _ => (self.point, self.point.project_onto(other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        week5::{scalar::Scalar as S, vector::Vector},
+        week7::{line::Line3, plane::Plane3, point::Point3},
+    };
+
+    use super::{Distance, Project};
+
+    fn line(point: Point3, direction: [f64; 3]) -> Line3 {
+        Line3::from_point_and_direction(point, Vector(direction.to_vec())).unwrap()
+    }
+
+    #[test]
+    fn point_distances() {
+        let p = Point3(1.0, -2.0, 4.0);
+        assert_approx_eq!(p.distance(&Point3(4.0, 2.0, 4.0)), S(5.0));
+
+        // stewart 12.5: |3 - 4 + 24 - 5| / 7
+        let plane = Plane3::from_equation(3.0, 2.0, 6.0, 5.0);
+        assert_approx_eq!(p.distance(&plane), S(18.0 / 7.0));
+        assert_approx_eq!(plane.distance(&p), S(18.0 / 7.0));
+        assert!(plane.contains(&p.closest_points(&plane).1));
+
+        let x_axis = line(Point3::ZERO, [2.0, 0.0, 0.0]);
+        assert_approx_eq!(Point3(1.0, 1.0, 1.0).distance(&x_axis), S(2.0_f64.sqrt()));
+        assert_approx_eq!(
+            x_axis.closest_points(&Point3(1.0, 1.0, 1.0)).0,
+            Point3(1.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn line_distances() {
+        // skew, with common normal (13, -6, -5)
+        let l1 = line(Point3(1.0, -2.0, 4.0), [1.0, 3.0, -1.0]);
+        let l2 = line(Point3(0.0, 3.0, -3.0), [2.0, 1.0, 4.0]);
+        assert_approx_eq!(l1.distance(&l2), S(8.0 / 230.0_f64.sqrt()));
+
+        let (on_l1, on_l2) = l1.closest_points(&l2);
+        assert!(l1.contains(&on_l1));
+        assert!(l2.contains(&on_l2));
+        assert_approx_eq!((on_l2 - on_l1).dotted(&l1.direction), S(0.0));
+
+        let parallel = line(Point3(0.0, 3.0, 0.0), [-1.0, 0.0, 0.0]);
+        let x_axis = line(Point3::ZERO, [1.0, 0.0, 0.0]);
+        assert_approx_eq!(x_axis.distance(&parallel), S(3.0));
+
+        let crossing = line(Point3(5.0, -1.0, 0.0), [0.0, 1.0, 0.0]);
+        assert_approx_eq!(x_axis.distance(&crossing), S(0.0));
+        assert_approx_eq!(x_axis.closest_points(&crossing).0, Point3(5.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn plane_distances() {
+        // stewart 12.5 example 9
+        let p1 = Plane3::from_equation(10.0, 2.0, -2.0, 5.0);
+        let p2 = Plane3::from_equation(5.0, 1.0, -1.0, 1.0);
+        assert_approx_eq!(p1.distance(&p2), S(3.0_f64.sqrt() / 6.0));
+
+        let floor = Plane3::from_equation(0.0, 0.0, 1.0, 0.0);
+        assert_approx_eq!(
+            floor.distance(&Plane3::from_equation(1.0, 0.0, 0.0, 7.0)),
+            S(0.0)
+        );
+
+        let above = line(Point3(0.0, 0.0, 2.0), [1.0, 1.0, 0.0]);
+        assert_approx_eq!(above.distance(&floor), S(2.0));
+        assert_approx_eq!(floor.distance(&above), S(2.0));
+        let falling = line(Point3(0.0, 0.0, 2.0), [1.0, 1.0, -1.0]);
+        assert_approx_eq!(falling.distance(&floor), S(0.0));
+        assert_approx_eq!(falling.closest_points(&floor).0, Point3(2.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn projections() {
+        let floor = Plane3::from_equation(0.0, 0.0, 1.0, 1.0);
+        let p = Point3(1.0, 2.0, 3.0);
+
+        assert_approx_eq!(p.project_onto(&floor), Point3(1.0, 2.0, 1.0));
+        assert_approx_eq!(p.reflect_across(&floor), Point3(1.0, 2.0, -1.0));
+
+        let diagonal = line(Point3::ZERO, [1.0, 1.0, 0.0]);
+        assert_approx_eq!(
+            Point3(2.0, 0.0, 0.0).project_onto(&diagonal),
+            Point3(1.0, 1.0, 0.0)
+        );
+        assert_approx_eq!(
+            Point3(2.0, 0.0, 0.0).reflect_across(&diagonal),
+            Point3(0.0, 2.0, 0.0)
+        );
+
+        let v = Vector(vec![1.0, 2.0, 3.0]);
+        assert_approx_eq!(v.project_onto(&floor), Vector(vec![1.0, 2.0, 0.0]));
+        assert_approx_eq!(v.reflect_across(&floor), Vector(vec![1.0, 2.0, -3.0]));
+        assert_approx_eq!(
+            Vector(vec![2.0, 0.0, 1.0]).project_onto(&diagonal),
+            Vector(vec![1.0, 1.0, 0.0])
+        );
+        assert_approx_eq!(
+            Vector(vec![2.0, 0.0, 1.0]).reflect_across(&diagonal),
+            Vector(vec![0.0, 2.0, -1.0])
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use crate::approx::{ApproxEq, Tolerance};
+use crate::week5::{scalar::Scalar as S, vector::Vector};
+use crate::week7::{line::Line3, plane::Plane3, point::Point3};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Intersection3 {
+    // the two objects are the same
+    Coincident,
+    // they never meet, staying the same distance apart
+    Parallel,
+    Point(Point3),
+    // also the result of a line lying in a plane
+    Line(Line3),
+    // lines that aren't parallel but still never meet
+    Skew { distance: f64 },
+    // three planes with no point in common, none of the above
+    Empty,
+}
+
+pub trait Intersect<Rhs = Self> {
+    fn intersect(&self, other: &Rhs) -> Intersection3 {
+        self.intersect_with(other, Tolerance::DEFAULT)
+    }
+
+    fn intersect_with(&self, other: &Rhs, tolerance: Tolerance) -> Intersection3;
+}
+
+impl Intersect for Plane3 {
+    fn intersect_with(&self, other: &Plane3, tolerance: Tolerance) -> Intersection3 {
+        if self.is_parallel_with(other, tolerance) {
+            return if self.contains_with(&other.point, tolerance) {
+                Intersection3::Coincident
+            } else {
+                Intersection3::Parallel
+            };
+        }
+
+        let (n1, n2) = (&self.normal, &other.normal);
+        let (d1, d2) = (self.as_equation().1 .0, other.as_equation().1 .0);
+        let (n1n1, n2n2, n1n2) = (n1.dotted(n1).0, n2.dotted(n2).0, n1.dotted(n2).0);
+        // the point on both planes closest to the origin, a combination of the two normals
+        let determinant = n1n1 * n2n2 - n1n2 * n1n2;
+        let offset = n1.multiplied(S((d1 * n2n2 - d2 * n1n2) / determinant))
+            + n2.multiplied(S((d2 * n1n1 - d1 * n1n2) / determinant));
+
+        Intersection3::Line(Line3 {
+            point: Point3::ZERO + offset,
+            direction: n1 ^ n2,
+        })
+    }
+}
+
+impl Intersect<Plane3> for Line3 {
+    fn intersect_with(&self, plane: &Plane3, tolerance: Tolerance) -> Intersection3 {
+        let along_normal = self.direction.dotted(&plane.normal);
+        // cosine of the angle between the direction and the normal
+        if (along_normal.clone() / (self.direction.magnitude() * plane.normal.magnitude()))
+            .approx_eq_with(&S(0.0), tolerance)
+        {
+            return if plane.contains_with(&self.point, tolerance) {
+                Intersection3::Line(self.clone())
+            } else {
+                Intersection3::Parallel
+            };
+        }
+
+        // n.(p + td) = n.p0
+        let t = (plane.point - self.point).dotted(&plane.normal) / along_normal;
+        Intersection3::Point(self.point_at(t.0))
+    }
+}
+
+impl Intersect<Line3> for Plane3 {
+    fn intersect_with(&self, line: &Line3, tolerance: Tolerance) -> Intersection3 {
+        line.intersect_with(self, tolerance)
+    }
+}
+
+impl Intersect for Line3 {
+    fn intersect_with(&self, other: &Line3, tolerance: Tolerance) -> Intersection3 {
+        if self.is_parallel_with(other, tolerance) {
+            return if self.contains_with(&other.point, tolerance) {
+                Intersection3::Coincident
+            } else {
+                Intersection3::Parallel
+            };
+        }
+
+        let normal = &self.direction ^ &other.direction;
+        let offset = other.point - self.point;
+        let distance = offset.dotted(&normal) / normal.magnitude();
+        if !distance.approx_eq_with(&S(0.0), tolerance) {
+            return Intersection3::Skew {
+                distance: distance.0.abs(),
+            };
+        }
+
+        // p1 + s d1 = p2 + t d2, crossing both sides with d2 leaves only s
+        let s = (&offset ^ &other.direction).dotted(&normal) / normal.magnitude_squared();
+        Intersection3::Point(self.point_at(s.0))
+    }
+}
+
+pub fn intersect_planes(p1: &Plane3, p2: &Plane3, p3: &Plane3) -> Intersection3 {
+    intersect_planes_with(p1, p2, p3, Tolerance::DEFAULT)
+}
+
+// Point if the normals are independent, otherwise the planes share a Line,
+// are all Coincident, or have nothing in common (Empty)
+pub fn intersect_planes_with(
+    p1: &Plane3,
+    p2: &Plane3,
+    p3: &Plane3,
+    tolerance: Tolerance,
+) -> Intersection3 {
+    let (n1, n2, n3) = (&p1.normal, &p2.normal, &p3.normal);
+    let triple = n1.dotted(&(n2 ^ n3));
+    let volume = triple.clone() / (n1.magnitude() * n2.magnitude() * n3.magnitude());
+
+    if !volume.approx_eq_with(&S(0.0), tolerance) {
+        // cramer's rule, written with cross products
+        let (d1, d2, d3) = (
+            p1.as_equation().1 .0,
+            p2.as_equation().1 .0,
+            p3.as_equation().1 .0,
+        );
+        let offset: Vector = ((n2 ^ n3).multiplied(S(d1))
+            + (n3 ^ n1).multiplied(S(d2))
+            + (n1 ^ n2).multiplied(S(d3)))
+        .multiplied(S(1.0 / triple.0));
+        return Intersection3::Point(Point3::ZERO + offset);
+    }
+
+    match p1.intersect_with(p2, tolerance) {
+        Intersection3::Coincident => match p1.intersect_with(p3, tolerance) {
+            Intersection3::Parallel => Intersection3::Empty,
+            other => other,
+        },
+        Intersection3::Line(line) => match line.intersect_with(p3, tolerance) {
+            Intersection3::Line(line) => Intersection3::Line(line),
+            _ => Intersection3::Empty,
+        },
+        _ => Intersection3::Empty,
+    }
+}
+
+impl ApproxEq for Intersection3 {
+    fn approx_eq_with(&self, other: &Intersection3, tolerance: Tolerance) -> bool {
+        match (self, other) {
+            (Intersection3::Point(a), Intersection3::Point(b)) => a.approx_eq_with(b, tolerance),
+            (Intersection3::Line(a), Intersection3::Line(b)) => a.approx_eq_with(b, tolerance),
+            (Intersection3::Skew { distance: a }, Intersection3::Skew { distance: b }) => {
+                a.approx_eq_with(b, tolerance)
+            }
+            (a, b) => std::mem::discriminant(a) == std::mem::discriminant(b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        week5::vector::Vector,
+        week7::{line::Line3, plane::Plane3, point::Point3},
+    };
+
+    use super::{intersect_planes, Intersect, Intersection3};
+
+    fn line(point: Point3, direction: [f64; 3]) -> Line3 {
+        Line3::from_point_and_direction(point, Vector(direction.to_vec())).unwrap()
+    }
+
+    #[test]
+    fn plane_plane() {
+        // stewart 12.5 example 7
+        let p1 = Plane3::from_equation(1.0, 1.0, 1.0, 1.0);
+        let p2 = Plane3::from_equation(1.0, -2.0, 3.0, 1.0);
+
+        assert_approx_eq!(
+            p1.intersect(&p2),
+            Intersection3::Line(line(Point3(1.0, 0.0, 0.0), [5.0, -2.0, -3.0]))
+        );
+        assert_eq!(p1.intersect(&p1), Intersection3::Coincident);
+        assert_eq!(
+            p1.intersect(&Plane3::from_equation(2.0, 2.0, 2.0, 5.0)),
+            Intersection3::Parallel
+        );
+    }
+
+    #[test]
+    fn line_plane() {
+        // stewart 12.5 example 6
+        let l = line(Point3(2.0, 0.0, 5.0), [3.0, -4.0, 1.0]);
+        let plane = Plane3::from_equation(4.0, 5.0, -2.0, 18.0);
+
+        assert_approx_eq!(
+            l.intersect(&plane),
+            Intersection3::Point(Point3(-4.0, 8.0, 3.0))
+        );
+        assert_approx_eq!(
+            plane.intersect(&l),
+            Intersection3::Point(Point3(-4.0, 8.0, 3.0))
+        );
+
+        let flat = Plane3::from_equation(0.0, 0.0, 1.0, 5.0);
+        let inside = line(Point3(0.0, 0.0, 5.0), [1.0, 2.0, 0.0]);
+        assert_eq!(inside.intersect(&flat), Intersection3::Line(inside.clone()));
+        assert_eq!(
+            line(Point3::ZERO, [1.0, 2.0, 0.0]).intersect(&flat),
+            Intersection3::Parallel
+        );
+    }
+
+    #[test]
+    fn line_line() {
+        // stewart 12.5 example 3
+        let l1 = line(Point3(1.0, -2.0, 4.0), [1.0, 3.0, -1.0]);
+        let l2 = line(Point3(0.0, 3.0, -3.0), [2.0, 1.0, 4.0]);
+        assert!(matches!(l1.intersect(&l2), Intersection3::Skew { .. }));
+
+        // both through (1, 1, 1)
+        let l3 = line(Point3(0.0, 1.0, 2.0), [1.0, 0.0, -1.0]);
+        let l4 = line(Point3(1.0, -1.0, 1.0), [0.0, 1.0, 0.0]);
+        assert_approx_eq!(
+            l3.intersect(&l4),
+            Intersection3::Point(Point3(1.0, 1.0, 1.0))
+        );
+
+        // z = 0 and z = 3, crossing when seen from above
+        let below = line(Point3::ZERO, [1.0, 0.0, 0.0]);
+        let above = line(Point3(0.0, 0.0, 3.0), [0.0, 1.0, 0.0]);
+        assert_approx_eq!(
+            below.intersect(&above),
+            Intersection3::Skew { distance: 3.0 }
+        );
+
+        let shifted = line(Point3(0.0, 1.0, 0.0), [-2.0, 0.0, 0.0]);
+        assert_eq!(below.intersect(&shifted), Intersection3::Parallel);
+        assert_eq!(
+            below.intersect(&line(Point3(4.0, 0.0, 0.0), [3.0, 0.0, 0.0])),
+            Intersection3::Coincident
+        );
+    }
+
+    #[test]
+    fn three_planes() {
+        let x = Plane3::from_equation(1.0, 0.0, 0.0, 1.0);
+        let y = Plane3::from_equation(0.0, 1.0, 0.0, 2.0);
+        let z = Plane3::from_equation(0.0, 0.0, 1.0, 3.0);
+        assert_approx_eq!(
+            intersect_planes(&x, &y, &z),
+            Intersection3::Point(Point3(1.0, 2.0, 3.0))
+        );
+        assert_approx_eq!(
+            intersect_planes(
+                &Plane3::from_equation(1.0, 1.0, 1.0, 6.0),
+                &Plane3::from_equation(2.0, -1.0, 3.0, 9.0),
+                &Plane3::from_equation(-1.0, 4.0, 2.0, 13.0),
+            ),
+            Intersection3::Point(Point3(1.0, 2.0, 3.0))
+        );
+
+        // a book: every page goes through the spine
+        let pages = [
+            Plane3::from_equation(1.0, 1.0, 0.0, 0.0),
+            Plane3::from_equation(1.0, -1.0, 0.0, 0.0),
+            Plane3::from_equation(1.0, 0.0, 0.0, 0.0),
+        ];
+        assert_approx_eq!(
+            intersect_planes(&pages[0], &pages[1], &pages[2]),
+            Intersection3::Line(line(Point3::ZERO, [0.0, 0.0, 1.0]))
+        );
+
+        // a triangular prism: each pair meets, but never all three
+        let prism = [
+            Plane3::from_equation(1.0, 0.0, 0.0, 0.0),
+            Plane3::from_equation(0.0, 1.0, 0.0, 0.0),
+            Plane3::from_equation(1.0, 1.0, 0.0, 1.0),
+        ];
+        assert_eq!(
+            intersect_planes(&prism[0], &prism[1], &prism[2]),
+            Intersection3::Empty
+        );
+
+        assert_eq!(intersect_planes(&x, &x, &x), Intersection3::Coincident);
+        assert_eq!(
+            intersect_planes(&x, &x, &Plane3::from_equation(2.0, 0.0, 0.0, 0.0)),
+            Intersection3::Empty
+        );
+        assert_approx_eq!(
+            intersect_planes(&x, &x, &y),
+            Intersection3::Line(line(Point3(1.0, 2.0, 0.0), [0.0, 0.0, 1.0]))
+        );
+    }
+}
@@ -0,0 +1,466 @@
+use std::fmt::Display;
+
+use crate::approx::{ApproxEq, Tolerance};
+use crate::error::MathError;
+use crate::week5::{scalar::Scalar as S, vector::Vector};
+use crate::week6::angle::Radians;
+use crate::week7::{plane::Plane3, point::Point3};
+
+#[derive(Debug, Clone)]
+pub struct Line3 {
+    pub point: Point3,
+    pub direction: Vector,
+}
+
+impl Line3 {
+    pub fn from_point_and_direction(point: Point3, direction: Vector) -> Result<Line3, MathError> {
+        if direction.0.len() != 3 {
+            return Err(MathError::UnsupportedDimension {
+                expected: 3,
+                found: direction.0.len(),
+            });
+        }
+        if direction.0.iter().all(|x| *x == 0.0) {
+            return Err(MathError::ZeroVector);
+        }
+        Ok(Line3 { point, direction })
+    }
+
+    // through p1 at t = 0 and p2 at t = 1
+    pub fn from_points(p1: Point3, p2: Point3) -> Result<Line3, MathError> {
+        Line3::from_point_and_direction(p1, p2 - p1)
+    }
+
+    // (x - x0)/a = (y - y0)/b = (z - z0)/c, where a zero component is written
+    // separately as x = x0 before the rest, eg. "x = 1, (y - 2)/3 = z/-4"
+    pub fn from_symmetric(equations: &str) -> Result<Line3, MathError> {
+        let equations: String = equations.chars().filter(|c| !c.is_whitespace()).collect();
+        // (x0, a) for each of x, y and z
+        let mut components: [Option<(f64, f64)>; 3] = [None; 3];
+
+        for clause in equations.split(',') {
+            let terms = clause
+                .split('=')
+                .map(SymmetricTerm::parse)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let mut set = |axis: usize, component: (f64, f64)| {
+                if components[axis].replace(component).is_some() {
+                    Err(MathError::Parse(format!(
+                        "{} appears more than once",
+                        AXES[axis]
+                    )))
+                } else {
+                    Ok(())
+                }
+            };
+
+            match terms[..] {
+                // x = x0
+                [SymmetricTerm::Variable { axis, x0, a }, SymmetricTerm::Constant(k)]
+                | [SymmetricTerm::Constant(k), SymmetricTerm::Variable { axis, x0, a }] => {
+                    set(axis, (x0 + a * k, 0.0))?
+                }
+                _ if terms.len() >= 2 => {
+                    for term in terms {
+                        match term {
+                            SymmetricTerm::Variable { axis, x0, a } => set(axis, (x0, a))?,
+                            SymmetricTerm::Constant(k) => {
+                                return Err(MathError::Parse(format!(
+                                    "unexpected constant {} in `{}`",
+                                    k, clause
+                                )))
+                            }
+                        }
+                    }
+                }
+                _ => return Err(MathError::Parse(format!("`{}` is not an equation", clause))),
+            }
+        }
+
+        // "x = 1, y = 2" leaves z free
+        let missing: Vec<usize> = (0..3).filter(|&i| components[i].is_none()).collect();
+        if let [axis] = missing[..] {
+            if components.iter().flatten().all(|(_, a)| *a == 0.0) {
+                components[axis] = Some((0.0, 1.0));
+            }
+        }
+
+        match components {
+            [Some((x0, a)), Some((y0, b)), Some((z0, c))] => {
+                Line3::from_point_and_direction(Point3(x0, y0, z0), Vector(vec![a, b, c]))
+            }
+            _ => Err(MathError::Parse(format!(
+                "`{}` doesn't pin down x, y and z",
+                equations
+            ))),
+        }
+    }
+
+    pub fn point_at(&self, t: f64) -> Point3 {
+        self.point + self.direction.multiplied(S(t))
+    }
+
+    pub fn contains(&self, point: &Point3) -> bool {
+        self.contains_with(point, Tolerance::DEFAULT)
+    }
+
+    pub fn contains_with(&self, point: &Point3, tolerance: Tolerance) -> bool {
+        // distance from the point to the line
+        let offset = point - &self.point;
+        ((&offset ^ &self.direction).magnitude() / self.direction.magnitude())
+            .approx_eq_with(&S(0.0), tolerance)
+    }
+
+    pub fn is_parallel(&self, other: &Line3) -> bool {
+        self.is_parallel_with(other, Tolerance::DEFAULT)
+    }
+
+    pub fn is_parallel_with(&self, other: &Line3, tolerance: Tolerance) -> bool {
+        // sine of the angle between the directions
+        ((&self.direction ^ &other.direction).magnitude()
+            / (self.direction.magnitude() * other.direction.magnitude()))
+        .approx_eq_with(&S(0.0), tolerance)
+    }
+
+    // neither parallel nor meeting anywhere
+    pub fn is_skew(&self, other: &Line3) -> bool {
+        self.is_skew_with(other, Tolerance::DEFAULT)
+    }
+
+    pub fn is_skew_with(&self, other: &Line3, tolerance: Tolerance) -> bool {
+        if self.is_parallel_with(other, tolerance) {
+            return false;
+        }
+        // distance between the lines, measured along their common normal
+        let normal = &self.direction ^ &other.direction;
+        let offset = other.point - self.point;
+        !(offset.dotted(&normal) / normal.magnitude()).approx_eq_with(&S(0.0), tolerance)
+    }
+
+    // folded into [0, pi/2], a line has no preferred direction
+    pub fn angle_to(&self, other: &Line3) -> Radians {
+        let cosine = self.direction.dotted(&other.direction)
+            / (self.direction.magnitude() * other.direction.magnitude());
+        Radians(cosine.0.abs().min(1.0).acos())
+    }
+
+    // the complement of the angle between the direction and the plane's normal
+    pub fn angle_to_plane(&self, plane: &Plane3) -> Radians {
+        let sine = self.direction.dotted(&plane.normal)
+            / (self.direction.magnitude() * plane.normal.magnitude());
+        Radians(sine.0.abs().min(1.0).asin())
+    }
+
+    pub fn closest_point_to(&self, point: &Point3) -> Point3 {
+        let offset = point - &self.point;
+        let t = offset.dotted(&self.direction) / self.direction.magnitude_squared();
+        self.point_at(t.0)
+    }
+
+    fn components(&self) -> [(f64, f64); 3] {
+        let Point3(x0, y0, z0) = self.point;
+        let d = &self.direction.0;
+        [(x0, d[0]), (y0, d[1]), (z0, d[2])]
+    }
+
+    // r = <x0, y0, z0> + t<a, b, c>
+    pub fn vector_form(&self) -> String {
+        let Point3(x0, y0, z0) = self.point;
+        let d = &self.direction.0;
+        format!(
+            "r = <{}, {}, {}> + t<{}, {}, {}>",
+            x0, y0, z0, d[0], d[1], d[2]
+        )
+    }
+
+    // x = x0 + at, y = y0 + bt, z = z0 + ct
+    pub fn parametric_form(&self) -> String {
+        self.components()
+            .iter()
+            .zip(AXES)
+            .map(|(&(x0, a), axis)| {
+                let t = match a.abs() {
+                    1.0 => "t".to_string(),
+                    a => format!("{}t", a),
+                };
+                let value = match (x0, a) {
+                    (x0, 0.0) => x0.to_string(),
+                    (0.0, a) if a < 0.0 => format!("-{}", t),
+                    (0.0, _) => t,
+                    (x0, a) if a < 0.0 => format!("{} - {}", x0, t),
+                    (x0, _) => format!("{} + {}", x0, t),
+                };
+                format!("{} = {}", axis, value)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    // (x - x0)/a = (y - y0)/b = (z - z0)/c
+    pub fn symmetric_form(&self) -> String {
+        let components = self.components();
+        let fixed = components
+            .iter()
+            .zip(AXES)
+            .filter(|((_, a), _)| *a == 0.0)
+            .map(|(&(x0, _), axis)| format!("{} = {}", axis, x0));
+        let ratios = components
+            .iter()
+            .zip(AXES)
+            .filter(|((_, a), _)| *a != 0.0)
+            .map(|(&(x0, a), axis)| {
+                let numerator = match x0 {
+                    0.0 => axis.to_string(),
+                    x0 if x0 < 0.0 => format!("({} + {})", axis, -x0),
+                    x0 => format!("({} - {})", axis, x0),
+                };
+                match a {
+                    1.0 => numerator.trim_matches(|c| c == '(' || c == ')').to_string(),
+                    a if a < 0.0 => format!("{}/({})", numerator, a),
+                    a => format!("{}/{}", numerator, a),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut clauses: Vec<String> = fixed.collect();
+        // with a single free coordinate there's nothing to equate it to
+        if ratios.len() > 1 {
+            clauses.push(ratios.join(" = "));
+        }
+        clauses.join(", ")
+    }
+}
+
+const AXES: [char; 3] = ['x', 'y', 'z'];
+
+#[derive(Debug, Clone, Copy)]
+enum SymmetricTerm {
+    // (axis - x0)/a
+    Variable { axis: usize, x0: f64, a: f64 },
+    Constant(f64),
+}
+
+impl SymmetricTerm {
+    fn parse(term: &str) -> Result<SymmetricTerm, MathError> {
+        let invalid = || MathError::Parse(format!("unexpected term `{}`", term));
+
+        if let Ok(k) = term.parse::<f64>() {
+            return Ok(SymmetricTerm::Constant(k));
+        }
+
+        let (numerator, a) = match term.rsplit_once('/') {
+            Some((numerator, denominator)) => {
+                let denominator = denominator
+                    .strip_prefix('(')
+                    .and_then(|d| d.strip_suffix(')'))
+                    .unwrap_or(denominator);
+                (
+                    numerator,
+                    denominator.parse::<f64>().map_err(|_| invalid())?,
+                )
+            }
+            None => (term, 1.0),
+        };
+        if a == 0.0 {
+            return Err(MathError::Parse(format!("`{}` divides by zero", term)));
+        }
+
+        let numerator = numerator
+            .strip_prefix('(')
+            .and_then(|n| n.strip_suffix(')'))
+            .unwrap_or(numerator);
+        let mut chars = numerator.chars();
+        let axis = chars
+            .next()
+            .and_then(|c| AXES.iter().position(|&axis| axis == c))
+            .ok_or_else(invalid)?;
+        let offset = match chars.as_str() {
+            "" => 0.0,
+            offset => offset.parse::<f64>().map_err(|_| invalid())?,
+        };
+
+        Ok(SymmetricTerm::Variable {
+            axis,
+            x0: -offset,
+            a,
+        })
+    }
+}
+
+impl Display for Line3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.vector_form())
+    }
+}
+
+impl PartialEq<Line3> for Line3 {
+    fn eq(&self, other: &Line3) -> bool {
+        self.approx_eq(other)
+    }
+}
+
+impl ApproxEq for Line3 {
+    fn approx_eq_with(&self, other: &Line3, tolerance: Tolerance) -> bool {
+        self.contains_with(&other.point, tolerance) && self.is_parallel_with(other, tolerance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, FRAC_PI_6};
+
+    use crate::{
+        error::MathError,
+        week5::vector::Vector,
+        week6::angle::Radians,
+        week7::{plane::Plane3, point::Point3},
+    };
+
+    use super::Line3;
+
+    #[test]
+    fn construction() {
+        let line = Line3::from_points(Point3(2.0, 4.0, -3.0), Point3(3.0, -1.0, 1.0)).unwrap();
+
+        assert_eq!(line.direction, Vector(vec![1.0, -5.0, 4.0]));
+        assert_eq!(line.point_at(0.0), Point3(2.0, 4.0, -3.0));
+        assert_eq!(line.point_at(1.0), Point3(3.0, -1.0, 1.0));
+        assert!(line.contains(&Point3(4.0, -6.0, 5.0)));
+        assert!(!line.contains(&Point3(4.0, -6.0, 4.0)));
+
+        assert_eq!(
+            Line3::from_points(Point3(1.0, 1.0, 1.0), Point3(1.0, 1.0, 1.0)).unwrap_err(),
+            MathError::ZeroVector
+        );
+        assert_eq!(
+            Line3::from_point_and_direction(Point3::ZERO, Vector(vec![1.0, 2.0])).unwrap_err(),
+            MathError::UnsupportedDimension {
+                expected: 3,
+                found: 2
+            }
+        );
+    }
+
+    #[test]
+    fn relative_position() {
+        // stewart 12.5 example 3
+        let l1 =
+            Line3::from_point_and_direction(Point3(1.0, -2.0, 4.0), Vector(vec![1.0, 3.0, -1.0]))
+                .unwrap();
+        let l2 =
+            Line3::from_point_and_direction(Point3(0.0, 3.0, 4.0), Vector(vec![2.0, 1.0, 4.0]))
+                .unwrap();
+        assert!(!l1.is_parallel(&l2));
+        assert!(l1.is_skew(&l2));
+
+        let meeting = Line3::from_points(Point3(1.0, -2.0, 4.0), Point3(5.0, 5.0, 5.0)).unwrap();
+        assert!(!l1.is_skew(&meeting));
+
+        let parallel =
+            Line3::from_point_and_direction(Point3::ZERO, Vector(vec![-2.0, -6.0, 2.0])).unwrap();
+        assert!(l1.is_parallel(&parallel));
+        assert!(!l1.is_skew(&parallel));
+        assert_ne!(l1, parallel);
+        assert_eq!(
+            l1,
+            Line3::from_point_and_direction(l1.point_at(3.0), Vector(vec![-1.0, -3.0, 1.0]))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn angles() {
+        let x_axis =
+            Line3::from_point_and_direction(Point3::ZERO, Vector(vec![1.0, 0.0, 0.0])).unwrap();
+        let diagonal =
+            Line3::from_point_and_direction(Point3(0.0, 0.0, 5.0), Vector(vec![-1.0, 1.0, 0.0]))
+                .unwrap();
+        assert_approx_eq!(x_axis.angle_to(&diagonal), Radians(FRAC_PI_4));
+        assert_approx_eq!(diagonal.angle_to(&x_axis), Radians(FRAC_PI_4));
+        assert_approx_eq!(x_axis.angle_to(&x_axis), Radians(0.0));
+
+        let floor = Plane3::from_equation(0.0, 0.0, 1.0, 0.0);
+        assert_approx_eq!(x_axis.angle_to_plane(&floor), Radians(0.0));
+        assert_approx_eq!(
+            Line3::from_point_and_direction(Point3::ZERO, Vector(vec![0.0, 0.0, -2.0]))
+                .unwrap()
+                .angle_to_plane(&floor),
+            Radians(FRAC_PI_2)
+        );
+        assert_approx_eq!(
+            Line3::from_point_and_direction(Point3::ZERO, Vector(vec![3.0_f64.sqrt(), 0.0, 1.0]))
+                .unwrap()
+                .angle_to_plane(&floor),
+            Radians(FRAC_PI_6)
+        );
+    }
+
+    #[test]
+    fn closest_point() {
+        let line =
+            Line3::from_point_and_direction(Point3::ZERO, Vector(vec![1.0, 1.0, 0.0])).unwrap();
+
+        assert_approx_eq!(
+            line.closest_point_to(&Point3(2.0, 0.0, 5.0)),
+            Point3(1.0, 1.0, 0.0)
+        );
+        assert_approx_eq!(
+            line.closest_point_to(&Point3(3.0, 3.0, 0.0)),
+            Point3(3.0, 3.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn forms() {
+        let line = Line3::from_points(Point3(2.0, 4.0, -3.0), Point3(3.0, -1.0, 1.0)).unwrap();
+
+        assert_eq!(line.vector_form(), "r = <2, 4, -3> + t<1, -5, 4>");
+        assert_eq!(line.to_string(), line.vector_form());
+        assert_eq!(line.parametric_form(), "x = 2 + t, y = 4 - 5t, z = -3 + 4t");
+        assert_eq!(line.symmetric_form(), "x - 2 = (y - 4)/(-5) = (z + 3)/4");
+
+        let flat =
+            Line3::from_point_and_direction(Point3(1.0, 0.0, 2.0), Vector(vec![0.0, 3.0, -1.0]))
+                .unwrap();
+        assert_eq!(flat.parametric_form(), "x = 1, y = 3t, z = 2 - t");
+        assert_eq!(flat.symmetric_form(), "x = 1, y/3 = (z - 2)/(-1)");
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(
+            Line3::from_symmetric("(x - 2)/1 = (y - 4)/-5 = (z + 3)/4").unwrap(),
+            Line3::from_points(Point3(2.0, 4.0, -3.0), Point3(3.0, -1.0, 1.0)).unwrap()
+        );
+
+        for line in [
+            Line3::from_points(Point3(2.0, 4.0, -3.0), Point3(3.0, -1.0, 1.0)).unwrap(),
+            Line3::from_point_and_direction(Point3(1.0, 0.0, 2.0), Vector(vec![0.0, 3.0, -1.0]))
+                .unwrap(),
+            Line3::from_point_and_direction(Point3(1.0, 2.0, 0.0), Vector(vec![0.0, 0.0, 1.0]))
+                .unwrap(),
+        ] {
+            let parsed = Line3::from_symmetric(&line.symmetric_form()).unwrap();
+            assert_eq!(parsed, line);
+            assert_eq!(parsed.symmetric_form(), line.symmetric_form());
+        }
+
+        assert!(matches!(
+            Line3::from_symmetric("(x - 1)/2 = (x + 1)/3 = z"),
+            Err(MathError::Parse(_))
+        ));
+        assert!(matches!(
+            Line3::from_symmetric("(x - 1)/0 = y = z"),
+            Err(MathError::Parse(_))
+        ));
+        assert!(matches!(
+            Line3::from_symmetric("x = 1, y = 2, z = 3"),
+            Err(MathError::ZeroVector)
+        ));
+        assert!(matches!(
+            Line3::from_symmetric("x + y = 2"),
+            Err(MathError::Parse(_))
+        ));
+    }
+}
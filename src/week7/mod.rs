@@ -1,7 +1,16 @@
+pub mod coordinates;
 pub mod cross;
 pub mod determinant;
+pub mod distance;
+pub mod intersection;
+pub mod line;
 pub mod plane;
 pub mod point;
+pub mod point_n;
+pub mod triple;
+
+// row reduction lives with the rest of the matrix code, this is where the problem sets look for it
+pub use crate::linalg::elimination;
 
 #[cfg(test)]
 mod test {
@@ -1,8 +1,15 @@
-use std::f64::EPSILON;
+use std::f64::consts::FRAC_PI_2;
 
+use crate::approx::{ApproxEq, Tolerance};
+use crate::error::MathError;
+use crate::linalg::matrix::Matrix;
 use crate::week5::{scalar::Scalar as S, vector::Vector};
+use crate::week6::angle::Radians;
 use crate::week7::point::Point3;
 
+use crate::week7::intersection::Intersect;
+pub use crate::week7::intersection::Intersection3;
+
 impl From<S> for f64 {
     fn from(s: S) -> Self {
         s.0
@@ -15,13 +22,6 @@ pub struct Plane3 {
     pub normal: Vector,
 }
 
-#[derive(Debug)]
-pub enum Intersection3 {
-    Coincident,
-    Parallel,
-    Point(Point3),
-}
-
 #[derive(Debug)]
 pub enum TwoValues {
     YZ(S, S),
@@ -29,128 +29,244 @@ pub enum TwoValues {
     XY(S, S),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CoordinatePlane {
+    // z = 0
+    XY,
+    // x = 0
+    YZ,
+    // y = 0
+    XZ,
+}
+
+impl CoordinatePlane {
+    pub fn as_plane(&self) -> Plane3 {
+        let (a, b, c) = match self {
+            CoordinatePlane::XY => (0.0, 0.0, 1.0),
+            CoordinatePlane::YZ => (1.0, 0.0, 0.0),
+            CoordinatePlane::XZ => (0.0, 1.0, 0.0),
+        };
+        // the normals here are never zero
+        Plane3::try_from_equation(a, b, c, 0.0).unwrap()
+    }
+}
+
 impl Plane3 {
-    pub fn from_point_and_normal(point: Point3, normal: Vector) -> Plane3 {
-        Plane3 {
+    pub fn try_from_point_and_normal(point: Point3, normal: Vector) -> Result<Plane3, MathError> {
+        if normal.0.len() != 3 {
+            return Err(MathError::UnsupportedDimension {
+                expected: 3,
+                found: normal.0.len(),
+            });
+        }
+        Ok(Plane3 {
             point,
-            normal: normal.into_unit_in_direction(),
+            normal: normal.try_into_unit()?,
+        })
+    }
+
+    // panics if the normal is zero or not 3d, try_from_point_and_normal reports that instead
+    pub fn from_point_and_normal(point: Point3, normal: Vector) -> Plane3 {
+        Plane3::try_from_point_and_normal(point, normal).expect("a plane needs a nonzero 3d normal")
+    }
+
+    pub fn try_from_points(p1: Point3, p2: Point3, p3: Point3) -> Result<Plane3, MathError> {
+        let (u, v) = (p2 - p1, p3 - p1);
+        let normal = &u ^ &v;
+        // compared against the edge lengths, so a small triangle isn't mistaken for a line
+        let scale = (u.magnitude() * v.magnitude()).0;
+        if scale == 0.0 || (normal.magnitude().0 / scale).approx_eq(&0.0) {
+            return Err(MathError::CollinearPoints);
         }
+        Plane3::try_from_point_and_normal(p1, normal)
     }
 
+    // panics if the points are collinear, try_from_points reports that instead
     pub fn from_points(p1: Point3, p2: Point3, p3: Point3) -> Plane3 {
-        Plane3 {
-            normal: ((p2 - p1) ^ (p3 - p1)).into_unit_in_direction(),
-            point: p1,
+        Plane3::try_from_points(p1, p2, p3).expect("a plane needs three non collinear points")
+    }
+
+    // least squares plane through a cloud of points: it passes through their centroid,
+    // and its normal is the direction the centered points vary the least in
+    pub fn fit(points: &[Point3]) -> Result<Plane3, MathError> {
+        if points.len() < 3 {
+            return Err(MathError::NotEnoughPoints {
+                needed: 3,
+                found: points.len(),
+            });
         }
+
+        let centroid = Point3::centroid(points)?;
+        let centered = points.iter().map(|p| p - &centroid).collect();
+
+        let svd = Matrix::from_rows(centered)?.svd()?;
+        // the points have to spread out in two directions: all in one spot leaves nothing, and
+        // along a line the second direction is only rounding noise
+        let (largest, middle) = (svd.singular_values[0], svd.singular_values[1]);
+        if largest == 0.0 || (middle / largest).approx_eq(&0.0) {
+            return Err(MathError::CollinearPoints);
+        }
+        Plane3::try_from_point_and_normal(centroid, svd.v.column_vector(2))
     }
 
-    // ax + by + cz = d
-    pub fn from_equation(mut ax: f64, mut by: f64, mut cz: f64, mut rhs: f64) -> Plane3 {
+    // ax + by + cz = d, with a, b and c not all zero
+    pub fn try_from_equation(
+        mut ax: f64,
+        mut by: f64,
+        mut cz: f64,
+        mut rhs: f64,
+    ) -> Result<Plane3, MathError> {
         let magnitude = (ax * ax + by * by + cz * cz).sqrt();
+        if magnitude == 0.0 {
+            return Err(MathError::ZeroVector);
+        }
         ax /= magnitude;
         by /= magnitude;
         cz /= magnitude;
         rhs /= magnitude;
         // let t = rhs / (3.0 * ax * by * cz);
 
-        Plane3 {
+        Ok(Plane3 {
             normal: Vector(vec![ax, by, cz]),
             point: Point3(ax * rhs, by * rhs, cz * rhs),
-        }
+        })
+    }
+
+    // panics if a, b and c are all zero, try_from_equation reports that instead
+    pub fn from_equation(ax: f64, by: f64, cz: f64, rhs: f64) -> Plane3 {
+        Plane3::try_from_equation(ax, by, cz, rhs).expect("a plane needs a nonzero normal")
     }
 
     pub fn contains(&self, point: &Point3) -> bool {
+        self.contains_with(point, Tolerance::DEFAULT)
+    }
+
+    pub fn contains_with(&self, point: &Point3, tolerance: Tolerance) -> bool {
         // satisfies:
         // ax + by + cz = d
-        // where normal = <a, b, c>
-        // and d = (self.point - &Point3::ZERO).dotted(&self.normal)
-        ((point - &Point3::ZERO).dotted(&self.normal)
-            - (self.point - &Point3::ZERO).dotted(&self.normal))
-        .0 <= EPSILON
+        // i.e. the vector from the plane's point to this one has no component along the normal
+        let distance = (point - &self.point).dotted(&self.normal) / self.normal.magnitude();
+        distance.approx_eq_with(&S(0.0), tolerance)
     }
 
     pub fn as_equation(&self) -> (&Vec<f64>, S) {
         // a(x - p0) + b(y - p1) + c(z - p2) = 0
         // ax - a*p0 + by - b*p1 + cz - c*p2 = 0
         // ax + by + cz = a*p0 + b*p1 + c*p2 = n.(p - 0)
-        (
-            &self.normal.0,
-            (self.point - &Point3::ZERO).dotted(&self.normal),
-        )
+        (&self.normal.0, self.point.to_vector().dotted(&self.normal))
     }
 
     // return the third value obtained by plugging the first two into the eqn
     // ax + by + cz = d
-    pub fn plug_values(&self, values: TwoValues) -> Option<S> {
+    pub fn plug_values(&self, values: TwoValues) -> Result<Option<S>, MathError> {
         let (coefficients, d) = self.as_equation();
-        if let [a, b, c] = coefficients[0..3] {
-            match values {
-                // (ax + by - d)/c = z
-                TwoValues::YZ(y, z) => (a != 0.0).then(|| (b * y + c * z - d) / a),
-                TwoValues::XZ(x, z) => (b != 0.0).then(|| (a * x + c * z - d) / b),
-                TwoValues::XY(x, y) => (c != 0.0).then(|| (a * x + b * y - d) / c),
-            }
+        let solvable = |coefficient: f64| !coefficient.approx_eq(&0.0);
+        if let [a, b, c] = coefficients[..] {
+            Ok(match values {
+                // (d - by - cz)/a = x
+                TwoValues::YZ(y, z) => solvable(a).then(|| (d - b * y - c * z) / a),
+                TwoValues::XZ(x, z) => solvable(b).then(|| (d - a * x - c * z) / b),
+                TwoValues::XY(x, y) => solvable(c).then(|| (d - a * x - b * y) / c),
+            })
         } else {
-            panic!()
+            Err(MathError::UnsupportedDimension {
+                expected: 3,
+                found: coefficients.len(),
+            })
         }
     }
 
-    pub fn axis_intersects(&self) -> (Intersection3, Intersection3, Intersection3) {
-        let on_origin = self.contains(&Point3::ZERO);
+    // where the plane crosses the x, y and z axes, in that order
+    pub fn axis_intersects(
+        &self,
+    ) -> Result<(Intersection3, Intersection3, Intersection3), MathError> {
+        // with no solution the axis either lies in the plane, or never reaches it
+        let intercept = |value: Option<S>, point: fn(f64) -> Point3| match value {
+            Some(value) => Intersection3::Point(point(value.into())),
+            None if self.contains(&Point3::ZERO) => Intersection3::Coincident,
+            None => Intersection3::Parallel,
+        };
+
+        Ok((
+            intercept(self.plug_values(TwoValues::YZ(S(0.0), S(0.0)))?, |x| {
+                Point3(x, 0.0, 0.0)
+            }),
+            intercept(self.plug_values(TwoValues::XZ(S(0.0), S(0.0)))?, |y| {
+                Point3(0.0, y, 0.0)
+            }),
+            intercept(self.plug_values(TwoValues::XY(S(0.0), S(0.0)))?, |z| {
+                Point3(0.0, 0.0, z)
+            }),
+        ))
+    }
+
+    // the line where the plane crosses a coordinate plane
+    pub fn trace(&self, coordinate_plane: CoordinatePlane) -> Intersection3 {
+        self.intersect(&coordinate_plane.as_plane())
+    }
+
+    // traces on the xy, yz and xz planes, in that order
+    pub fn traces(&self) -> (Intersection3, Intersection3, Intersection3) {
         (
-            match self.plug_values(TwoValues::YZ(S(0.0), S(0.0))) {
-                Some(x) => Intersection3::Point(Point3(x.into(), 0.0, 0.0)),
-                None => {
-                    if on_origin {
-                        Intersection3::Coincident
-                    } else {
-                        Intersection3::Parallel
-                    }
-                }
-            },
-            match self.plug_values(TwoValues::YZ(S(0.0), S(0.0))) {
-                Some(y) => Intersection3::Point(Point3(0.0, y.into(), 0.0)),
-                None => {
-                    if on_origin {
-                        Intersection3::Coincident
-                    } else {
-                        Intersection3::Parallel
-                    }
-                }
-            },
-            match self.plug_values(TwoValues::YZ(S(0.0), S(0.0))) {
-                Some(z) => Intersection3::Point(Point3(0.0, 0.0, z.into())),
-                None => {
-                    if on_origin {
-                        Intersection3::Coincident
-                    } else {
-                        Intersection3::Parallel
-                    }
-                }
-            },
+            self.trace(CoordinatePlane::XY),
+            self.trace(CoordinatePlane::YZ),
+            self.trace(CoordinatePlane::XZ),
         )
     }
 
     pub fn is_orthogonal(&self, other: &Plane3) -> bool {
-        self.normal.dotted(&other.normal) == S(0.0)
+        self.is_orthogonal_with(other, Tolerance::DEFAULT)
+    }
+
+    pub fn is_orthogonal_with(&self, other: &Plane3, tolerance: Tolerance) -> bool {
+        self.angle_to(other)
+            .approx_eq_with(&Radians(FRAC_PI_2), tolerance)
+    }
+
+    // the dihedral angle, folded into [0, pi/2] since either normal could be flipped
+    pub fn angle_to(&self, other: &Plane3) -> Radians {
+        let cosine = self.normal.dotted(&other.normal)
+            / (self.normal.magnitude() * other.normal.magnitude());
+        Radians(cosine.0.abs().min(1.0).acos())
     }
 
     pub fn is_parallel(&self, other: &Plane3) -> bool {
-        (&self.normal ^ &other.normal).magnitude_squared().0 <= EPSILON
+        self.is_parallel_with(other, Tolerance::DEFAULT)
+    }
+
+    pub fn is_parallel_with(&self, other: &Plane3, tolerance: Tolerance) -> bool {
+        // sine of the angle between the normals
+        ((&self.normal ^ &other.normal).magnitude()
+            / (self.normal.magnitude() * other.normal.magnitude()))
+        .approx_eq_with(&S(0.0), tolerance)
     }
 }
 
 impl PartialEq<Plane3> for Plane3 {
     fn eq(&self, other: &Plane3) -> bool {
-        self.contains(&other.point) && (self.is_parallel(other))
+        self.approx_eq(other)
+    }
+}
+
+impl ApproxEq for Plane3 {
+    fn approx_eq_with(&self, other: &Plane3, tolerance: Tolerance) -> bool {
+        self.contains_with(&other.point, tolerance) && self.is_parallel_with(other, tolerance)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{week5::vector::Vector, week7::point::Point3};
+    use std::f64::consts::FRAC_PI_4;
+
+    use crate::{
+        approx::Tolerance,
+        error::MathError,
+        week5::{scalar::Scalar as S, vector::Vector},
+        week6::angle::Radians,
+        week7::{line::Line3, point::Point3},
+    };
 
-    use super::Plane3;
+    use super::{CoordinatePlane, Intersection3, Plane3, TwoValues};
 
     #[test]
     fn contains() {
@@ -161,6 +277,37 @@ mod test {
 
         assert!(plane.contains(&plane.point));
         assert!(plane.contains(&Point3(1.0, 0.0, -1.0)));
+        assert!(plane.contains(&Point3(6.0, 4.0, -1.0)));
+
+        // on either side of the plane
+        assert!(!plane.contains(&Point3(0.0, 0.0, 0.0)));
+        assert!(!plane.contains(&Point3(2.0, 0.0, -1.0)));
+    }
+
+    #[test]
+    fn orthogonal() {
+        let plane = Plane3::from_equation(1.0, 1.0, 1.0, 0.0);
+        let other = Plane3::from_equation(1.0, -2.0, 1.0, 5.0);
+
+        assert!(plane.is_orthogonal(&other));
+        assert!(!plane.is_orthogonal(&plane));
+    }
+
+    #[test]
+    fn angle() {
+        // stewart 12.5 example 7: cos = 2 / (sqrt(3) sqrt(14))
+        let p1 = Plane3::from_equation(1.0, 1.0, 1.0, 1.0);
+        let p2 = Plane3::from_equation(1.0, -2.0, 3.0, 1.0);
+        assert_approx_eq!(p1.angle_to(&p2), Radians((2.0 / 42.0_f64.sqrt()).acos()));
+        assert_approx_eq!(p1.angle_to(&p1), Radians(0.0));
+
+        // flipping a normal doesn't change the plane, or the angle
+        let flipped = Plane3::from_equation(-1.0, 2.0, -3.0, -1.0);
+        assert_approx_eq!(p1.angle_to(&flipped), p1.angle_to(&p2));
+
+        let floor = Plane3::from_equation(0.0, 0.0, 1.0, 0.0);
+        let ramp = Plane3::from_equation(0.0, 1.0, 1.0, 4.0);
+        assert_approx_eq!(floor.angle_to(&ramp), Radians(FRAC_PI_4));
     }
 
     #[test]
@@ -182,6 +329,51 @@ mod test {
         assert_eq!(plane, other);
     }
 
+    #[test]
+    fn degenerate_construction() {
+        assert!(matches!(
+            Plane3::try_from_points(
+                Point3(1.0, 1.0, 1.0),
+                Point3(2.0, 3.0, 4.0),
+                Point3(4.0, 7.0, 10.0)
+            ),
+            Err(MathError::CollinearPoints)
+        ));
+        assert!(matches!(
+            Plane3::try_from_points(Point3::ZERO, Point3::ZERO, Point3(1.0, 0.0, 0.0)),
+            Err(MathError::CollinearPoints)
+        ));
+        // small, but still a proper triangle
+        assert!(Plane3::try_from_points(
+            Point3::ZERO,
+            Point3(1e-6, 0.0, 0.0),
+            Point3(0.0, 1e-6, 0.0)
+        )
+        .is_ok());
+
+        assert!(matches!(
+            Plane3::try_from_point_and_normal(Point3::ZERO, Vector(vec![0.0, 0.0, 0.0])),
+            Err(MathError::ZeroVector)
+        ));
+        assert!(matches!(
+            Plane3::try_from_point_and_normal(Point3::ZERO, Vector(vec![0.0, 1.0])),
+            Err(MathError::UnsupportedDimension {
+                expected: 3,
+                found: 2
+            })
+        ));
+        assert!(matches!(
+            Plane3::try_from_equation(0.0, 0.0, 0.0, 1.0),
+            Err(MathError::ZeroVector)
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "a plane needs a nonzero normal")]
+    fn from_equation_panics_without_a_normal() {
+        Plane3::from_equation(0.0, 0.0, 0.0, 0.0);
+    }
+
     #[test]
     fn construction() {
         let plane = Plane3::from_points(
@@ -224,4 +416,136 @@ mod test {
             panic!()
         }
     }
+
+    #[test]
+    fn fit() {
+        // z = x + 2y + 1, nudged up and down alternately
+        let points: Vec<Point3> = (0..12)
+            .map(|i| {
+                let (x, y) = ((i % 4) as f64, (i / 4) as f64);
+                let noise = if i % 2 == 0 { 0.01 } else { -0.01 };
+                Point3(x, y, x + 2.0 * y + 1.0 + noise)
+            })
+            .collect();
+        let plane = Plane3::fit(&points).unwrap();
+        let expected = Plane3::from_equation(1.0, 2.0, -1.0, -1.0);
+
+        assert!(plane.is_parallel_with(&expected, Tolerance::absolute(1e-2)));
+        assert!(expected.contains_with(&plane.point, Tolerance::absolute(1e-2)));
+
+        let exact = Plane3::fit(&[
+            Point3(1.0, 0.0, -1.0),
+            Point3(0.0, 1.0, 2.0),
+            Point3(3.0, 1.0, -2.0),
+            Point3(6.0, 4.0, -1.0),
+        ])
+        .unwrap();
+        assert_eq!(
+            exact,
+            Plane3::from_point_and_normal(Point3(1.0, 0.0, -1.0), Vector(vec![-4.0, 5.0, -3.0]))
+        );
+
+        let line: Vec<Point3> = (0..4)
+            .map(|t| Point3(t as f64, t as f64, t as f64))
+            .collect();
+        assert_eq!(Plane3::fit(&line).unwrap_err(), MathError::CollinearPoints);
+        assert_eq!(
+            Plane3::fit(&[Point3(1.0, 2.0, 3.0); 4]).unwrap_err(),
+            MathError::CollinearPoints
+        );
+
+        assert_eq!(
+            Plane3::fit(&points[..2]).unwrap_err(),
+            MathError::NotEnoughPoints {
+                needed: 3,
+                found: 2
+            }
+        );
+    }
+
+    #[test]
+    fn plug_values() {
+        let plane = Plane3::from_equation(2.0, -1.0, 3.0, 6.0);
+
+        assert_approx_eq!(
+            plane
+                .plug_values(TwoValues::YZ(S(1.0), S(1.0)))
+                .unwrap()
+                .unwrap(),
+            S(2.0)
+        );
+        assert_approx_eq!(
+            plane
+                .plug_values(TwoValues::XZ(S(1.0), S(2.0)))
+                .unwrap()
+                .unwrap(),
+            S(2.0)
+        );
+        assert_approx_eq!(
+            plane
+                .plug_values(TwoValues::XY(S(0.0), S(0.0)))
+                .unwrap()
+                .unwrap(),
+            S(2.0)
+        );
+        assert_eq!(
+            Plane3::from_equation(1.0, 1.0, 0.0, 2.0).plug_values(TwoValues::XY(S(0.0), S(0.0))),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn axis_intersects() {
+        // stewart 12.5 example 5: 12x + 15y + 20z = 60 has intercepts 5, 4 and 3
+        let (x, y, z) = Plane3::from_equation(12.0, 15.0, 20.0, 60.0)
+            .axis_intersects()
+            .unwrap();
+        assert_approx_eq!(x, Intersection3::Point(Point3(5.0, 0.0, 0.0)));
+        assert_approx_eq!(y, Intersection3::Point(Point3(0.0, 4.0, 0.0)));
+        assert_approx_eq!(z, Intersection3::Point(Point3(0.0, 0.0, 3.0)));
+
+        // through the origin, every intercept is the origin
+        let (x, y, z) = Plane3::from_equation(1.0, -2.0, 5.0, 0.0)
+            .axis_intersects()
+            .unwrap();
+        for intercept in [x, y, z] {
+            assert_approx_eq!(intercept, Intersection3::Point(Point3::ZERO));
+        }
+
+        // x + y = 2 runs alongside the z axis
+        let (x, y, z) = Plane3::from_equation(1.0, 1.0, 0.0, 2.0)
+            .axis_intersects()
+            .unwrap();
+        assert_approx_eq!(x, Intersection3::Point(Point3(2.0, 0.0, 0.0)));
+        assert_approx_eq!(y, Intersection3::Point(Point3(0.0, 2.0, 0.0)));
+        assert_eq!(z, Intersection3::Parallel);
+
+        // x = y contains it
+        let (_, _, z) = Plane3::from_equation(1.0, -1.0, 0.0, 0.0)
+            .axis_intersects()
+            .unwrap();
+        assert_eq!(z, Intersection3::Coincident);
+    }
+
+    #[test]
+    fn traces() {
+        let (xy, yz, xz) = Plane3::from_equation(12.0, 15.0, 20.0, 60.0).traces();
+        let through = |p1, p2| Intersection3::Line(Line3::from_points(p1, p2).unwrap());
+
+        assert_approx_eq!(xy, through(Point3(5.0, 0.0, 0.0), Point3(0.0, 4.0, 0.0)));
+        assert_approx_eq!(yz, through(Point3(0.0, 4.0, 0.0), Point3(0.0, 0.0, 3.0)));
+        assert_approx_eq!(xz, through(Point3(5.0, 0.0, 0.0), Point3(0.0, 0.0, 3.0)));
+
+        // z = 3 floats above the xy plane and cuts the others along horizontal lines
+        let lid = Plane3::from_equation(0.0, 0.0, 1.0, 3.0);
+        assert_eq!(lid.trace(CoordinatePlane::XY), Intersection3::Parallel);
+        assert_approx_eq!(
+            lid.trace(CoordinatePlane::YZ),
+            through(Point3(0.0, 0.0, 3.0), Point3(0.0, 1.0, 3.0))
+        );
+        assert_eq!(
+            Plane3::from_equation(0.0, 0.0, 2.0, 0.0).trace(CoordinatePlane::XY),
+            Intersection3::Coincident
+        );
+    }
 }
@@ -1,25 +1,136 @@
 use std::ops::{Add, Sub};
 
-use crate::week5::vector::Vector;
+use crate::{
+    error::MathError,
+    week5::{
+        vector::Vector,
+        vector_n::{Vector2, Vector3},
+    },
+    week7::point_n::Point,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point2(pub f64, pub f64);
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Point3(pub f64, pub f64, pub f64);
 
 impl Vector {
+    // the vector from p1 to p2
     pub fn from_points(p1: Point3, p2: Point3) -> Vector {
-        &p2 - &p1
+        p2 - p1
+    }
+}
+
+impl Point2 {
+    pub const ZERO: Point2 = Point2(0.0, 0.0);
+
+    pub fn checked_add(self, rhs: Vector) -> Result<Point2, MathError> {
+        Ok(self + Vector2::try_from(&rhs)?)
+    }
+
+    pub fn checked_sub(self, rhs: Vector) -> Result<Point2, MathError> {
+        Ok(self - Vector2::try_from(&rhs)?)
+    }
+
+    // the vector from the origin to this point
+    pub fn to_vector(self) -> Vector {
+        Vector(vec![self.0, self.1])
+    }
+
+    pub fn from_vector(position: &Vector) -> Result<Point2, MathError> {
+        Ok(Point::from_vector(Vector2::try_from(position)?).into())
+    }
+
+    pub fn midpoint(self, other: Point2) -> Point2 {
+        Point::from(self).midpoint(other.into()).into()
+    }
+
+    pub fn barycentric(points: &[Point2], weights: &[f64]) -> Result<Point2, MathError> {
+        let points: Vec<Point<2>> = points.iter().map(|&p| p.into()).collect();
+        Point::barycentric(&points, weights).map(Point2::from)
+    }
+
+    pub fn centroid(points: &[Point2]) -> Result<Point2, MathError> {
+        let points: Vec<Point<2>> = points.iter().map(|&p| p.into()).collect();
+        Point::centroid(&points).map(Point2::from)
     }
 }
 
 impl Point3 {
     pub const ZERO: Point3 = Point3(0.0, 0.0, 0.0);
+
+    pub fn checked_add(self, rhs: Vector) -> Result<Point3, MathError> {
+        Ok(self + Vector3::try_from(&rhs)?)
+    }
+
+    pub fn checked_sub(self, rhs: Vector) -> Result<Point3, MathError> {
+        Ok(self - Vector3::try_from(&rhs)?)
+    }
+
+    // the vector from the origin to this point
+    pub fn to_vector(self) -> Vector {
+        Vector(vec![self.0, self.1, self.2])
+    }
+
+    pub fn from_vector(position: &Vector) -> Result<Point3, MathError> {
+        Ok(Point::from_vector(Vector3::try_from(position)?).into())
+    }
+
+    pub fn midpoint(self, other: Point3) -> Point3 {
+        Point::from(self).midpoint(other.into()).into()
+    }
+
+    pub fn barycentric(points: &[Point3], weights: &[f64]) -> Result<Point3, MathError> {
+        let points: Vec<Point<3>> = points.iter().map(|&p| p.into()).collect();
+        Point::barycentric(&points, weights).map(Point3::from)
+    }
+
+    pub fn centroid(points: &[Point3]) -> Result<Point3, MathError> {
+        let points: Vec<Point<3>> = points.iter().map(|&p| p.into()).collect();
+        Point::centroid(&points).map(Point3::from)
+    }
+}
+
+impl From<Point2> for Point<2> {
+    fn from(p: Point2) -> Self {
+        Point([p.0, p.1])
+    }
+}
+
+impl From<Point<2>> for Point2 {
+    fn from(Point([x, y]): Point<2>) -> Self {
+        Point2(x, y)
+    }
+}
+
+impl From<Point3> for Point<3> {
+    fn from(p: Point3) -> Self {
+        Point([p.0, p.1, p.2])
+    }
+}
+
+impl From<Point<3>> for Point3 {
+    fn from(Point([x, y, z]): Point<3>) -> Self {
+        Point3(x, y, z)
+    }
+}
+
+// ========== point - point operations ==========
+
+impl Sub for Point2 {
+    type Output = Vector;
+
+    fn sub(self, rhs: Point2) -> Self::Output {
+        Vector(vec![self.0 - rhs.0, self.1 - rhs.1])
+    }
 }
 
 impl Sub<Point3> for Point3 {
     type Output = Vector;
 
     fn sub(self, rhs: Point3) -> Self::Output {
-        Vector(vec![rhs.0 - self.0, rhs.1 - self.1, rhs.2 - self.2])
+        Vector(vec![self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2])
     }
 }
 
@@ -27,7 +138,7 @@ impl Sub<&Point3> for Point3 {
     type Output = Vector;
 
     fn sub(self, rhs: &Point3) -> Self::Output {
-        Vector(vec![rhs.0 - self.0, rhs.1 - self.1, rhs.2 - self.2])
+        self - *rhs
     }
 }
 
@@ -35,7 +146,41 @@ impl Sub<&Point3> for &Point3 {
     type Output = Vector;
 
     fn sub(self, rhs: &Point3) -> Self::Output {
-        Vector(vec![rhs.0 - self.0, rhs.1 - self.1, rhs.2 - self.2])
+        *self - *rhs
+    }
+}
+
+// ========== point - vector operations ==========
+
+impl Add<Vector> for Point2 {
+    type Output = Point2;
+
+    fn add(self, rhs: Vector) -> Self::Output {
+        self.checked_add(rhs).unwrap()
+    }
+}
+
+impl Sub<Vector> for Point2 {
+    type Output = Point2;
+
+    fn sub(self, rhs: Vector) -> Self::Output {
+        self.checked_sub(rhs).unwrap()
+    }
+}
+
+impl Add<Vector2> for Point2 {
+    type Output = Point2;
+
+    fn add(self, rhs: Vector2) -> Self::Output {
+        (Point::from(self) + rhs).into()
+    }
+}
+
+impl Sub<Vector2> for Point2 {
+    type Output = Point2;
+
+    fn sub(self, rhs: Vector2) -> Self::Output {
+        (Point::from(self) - rhs).into()
     }
 }
 
@@ -43,10 +188,102 @@ impl Add<Vector> for Point3 {
     type Output = Point3;
 
     fn add(self, rhs: Vector) -> Self::Output {
-        if rhs.0.len() != 3 {
-            panic!()
-        };
+        self.checked_add(rhs).unwrap()
+    }
+}
+
+impl Sub<Vector> for Point3 {
+    type Output = Point3;
+
+    fn sub(self, rhs: Vector) -> Self::Output {
+        self.checked_sub(rhs).unwrap()
+    }
+}
+
+impl Add<Vector3> for Point3 {
+    type Output = Point3;
+
+    fn add(self, rhs: Vector3) -> Self::Output {
+        (Point::from(self) + rhs).into()
+    }
+}
+
+impl Sub<Vector3> for Point3 {
+    type Output = Point3;
+
+    fn sub(self, rhs: Vector3) -> Self::Output {
+        (Point::from(self) - rhs).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::MathError,
+        week5::{vector::Vector, vector_n::VectorN},
+    };
+
+    use super::{Point2, Point3};
+
+    #[test]
+    fn subtraction() {
+        let p = Point3(1.0, 2.0, 3.0);
+        let q = Point3(4.0, 6.0, 3.0);
+
+        // p - q points from q to p
+        assert_eq!(p - q, Vector(vec![-3.0, -4.0, 0.0]));
+        assert_eq!(Vector::from_points(p, q), Vector(vec![3.0, 4.0, 0.0]));
+        assert_eq!(p + (q - p), q);
+        assert_eq!(q - (q - p), p);
+        assert_eq!(Point2(1.0, 1.0) - Point2(0.0, 2.0), Vector(vec![1.0, -1.0]));
+    }
+
+    #[test]
+    fn vector_offsets() {
+        assert_eq!(
+            Point3(1.0, 2.0, 3.0) + VectorN([1.0, 1.0, 1.0]),
+            Point3(2.0, 3.0, 4.0)
+        );
+        assert_eq!(Point2(1.0, 2.0) - VectorN([1.0, 1.0]), Point2(0.0, 1.0));
+        assert_eq!(
+            Point3::ZERO.checked_add(Vector(vec![1.0, 2.0])),
+            Err(MathError::DimensionMismatch { left: 3, right: 2 })
+        );
+        assert_eq!(
+            Point2::ZERO.checked_sub(Vector(vec![1.0, 2.0])),
+            Ok(Point2(-1.0, -2.0))
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let p = Point3(1.0, -2.0, 0.5);
+
+        assert_eq!(p.to_vector(), Vector(vec![1.0, -2.0, 0.5]));
+        assert_eq!(Point3::from_vector(&p.to_vector()), Ok(p));
+        assert_eq!(
+            Point2::from_vector(&p.to_vector()),
+            Err(MathError::DimensionMismatch { left: 2, right: 3 })
+        );
+    }
+
+    #[test]
+    fn combinations() {
+        let (a, b, c) = (
+            Point3(0.0, 0.0, 0.0),
+            Point3(6.0, 0.0, 0.0),
+            Point3(0.0, 3.0, 3.0),
+        );
 
-        Point3(self.0 + rhs.0[0], self.1 + rhs.0[1], self.2 + rhs.0[2])
+        assert_eq!(a.midpoint(b), Point3(3.0, 0.0, 0.0));
+        assert_approx_eq!(Point3::centroid(&[a, b, c]).unwrap(), Point3(2.0, 1.0, 1.0));
+        assert_approx_eq!(
+            Point3::barycentric(&[a, b, c], &[0.5, 0.25, 0.25]).unwrap(),
+            Point3(1.5, 0.75, 0.75)
+        );
+        assert_eq!(
+            Point2::midpoint(Point2(0.0, 0.0), Point2(1.0, 3.0)),
+            Point2(0.5, 1.5)
+        );
     }
 }
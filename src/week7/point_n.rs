@@ -0,0 +1,135 @@
+use std::ops::{Add, Sub};
+
+use crate::approx::ApproxEq;
+use crate::error::MathError;
+use crate::week5::vector_n::VectorN;
+
+// a position, as opposed to a displacement: points can be subtracted from each other and moved
+// by vectors, but never added together or scaled
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point<const N: usize>(pub [f64; N]);
+
+impl<const N: usize> Point<N> {
+    pub const ZERO: Point<N> = Point([0.0; N]);
+
+    // the vector from the origin to this point
+    pub fn to_vector(self) -> VectorN<N> {
+        VectorN(self.0)
+    }
+
+    pub fn from_vector(position: VectorN<N>) -> Point<N> {
+        Point(position.0)
+    }
+
+    pub fn midpoint(self, other: Point<N>) -> Point<N> {
+        self + (other - self) * 0.5
+    }
+
+    // sum of weights[i] * points[i], where the weights have to add up to 1
+    pub fn barycentric(points: &[Point<N>], weights: &[f64]) -> Result<Point<N>, MathError> {
+        if points.len() != weights.len() {
+            return Err(MathError::DimensionMismatch {
+                left: points.len(),
+                right: weights.len(),
+            });
+        }
+        let Some(&first) = points.first() else {
+            return Err(MathError::NotEnoughPoints {
+                needed: 1,
+                found: 0,
+            });
+        };
+        let sum: f64 = weights.iter().sum();
+        if !sum.approx_eq(&1.0) {
+            return Err(MathError::UnnormalizedWeights { sum });
+        }
+
+        // measured from the first point, so only differences of points are ever scaled
+        Ok(points
+            .iter()
+            .zip(weights)
+            .fold(first, |combination, (&point, &weight)| {
+                combination + (point - first) * weight
+            }))
+    }
+
+    pub fn centroid(points: &[Point<N>]) -> Result<Point<N>, MathError> {
+        let weight = 1.0 / points.len() as f64;
+        Point::barycentric(points, &vec![weight; points.len()])
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = VectorN<N>;
+
+    fn sub(self, rhs: Point<N>) -> Self::Output {
+        VectorN(std::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<const N: usize> Add<VectorN<N>> for Point<N> {
+    type Output = Point<N>;
+
+    fn add(self, rhs: VectorN<N>) -> Self::Output {
+        Point(std::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<const N: usize> Sub<VectorN<N>> for Point<N> {
+    type Output = Point<N>;
+
+    fn sub(self, rhs: VectorN<N>) -> Self::Output {
+        Point(std::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{error::MathError, week5::vector_n::VectorN};
+
+    use super::Point;
+
+    #[test]
+    fn affine_algebra() {
+        let p = Point([1.0, 2.0, 3.0]);
+        let q = Point([4.0, 6.0, 3.0]);
+
+        assert_eq!(q - p, VectorN([3.0, 4.0, 0.0]));
+        assert_eq!(p + (q - p), q);
+        assert_eq!(q - (q - p), p);
+        assert_eq!(Point::from_vector(p.to_vector()), p);
+        assert_eq!(p.midpoint(q), Point([2.5, 4.0, 3.0]));
+    }
+
+    #[test]
+    fn combinations() {
+        let triangle = [Point([0.0, 0.0]), Point([3.0, 0.0]), Point([0.0, 3.0])];
+
+        assert_approx_eq!(Point::centroid(&triangle).unwrap(), Point([1.0, 1.0]));
+        assert_approx_eq!(
+            Point::barycentric(&triangle, &[0.0, 0.5, 0.5]).unwrap(),
+            Point([1.5, 1.5])
+        );
+        // weights outside [0, 1] land outside the triangle
+        assert_approx_eq!(
+            Point::barycentric(&triangle, &[-1.0, 1.0, 1.0]).unwrap(),
+            Point([3.0, 3.0])
+        );
+
+        assert_eq!(
+            Point::barycentric(&triangle, &[1.0, 1.0, 1.0]),
+            Err(MathError::UnnormalizedWeights { sum: 3.0 })
+        );
+        assert_eq!(
+            Point::barycentric(&triangle, &[1.0]),
+            Err(MathError::DimensionMismatch { left: 3, right: 1 })
+        );
+        assert_eq!(
+            Point::<2>::centroid(&[]),
+            Err(MathError::NotEnoughPoints {
+                needed: 1,
+                found: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use crate::approx::{ApproxEq, Tolerance};
+use crate::error::MathError;
+use crate::week5::{scalar::Scalar as S, vector::Vector};
+use crate::week7::point::Point3;
+
+impl Vector {
+    // u . (v x w), the signed volume of the parallelepiped the three span
+    pub fn scalar_triple_product(&self, v: &Vector, w: &Vector) -> Result<S, MathError> {
+        // the same error whichever of the three isn't 3d
+        if let Some(found) = [self, v, w].iter().map(|x| x.0.len()).find(|&n| n != 3) {
+            return Err(MathError::UnsupportedDimension { expected: 3, found });
+        }
+        Ok(self.dotted(&v.crossed(w)?))
+    }
+
+    // u x (v x w) = v(u . w) - w(u . v)
+    pub fn vector_triple_product(&self, v: &Vector, w: &Vector) -> Result<Vector, MathError> {
+        self.crossed(&v.crossed(w)?)
+    }
+}
+
+// the parallelogram with sides ab and ac
+pub fn parallelogram_area(a: &Point3, b: &Point3, c: &Point3) -> S {
+    ((b - a) ^ (c - a)).magnitude()
+}
+
+pub fn triangle_area(a: &Point3, b: &Point3, c: &Point3) -> S {
+    parallelogram_area(a, b, c) / 2.0
+}
+
+pub fn parallelepiped_volume(u: &Vector, v: &Vector, w: &Vector) -> Result<S, MathError> {
+    Ok(S(u.scalar_triple_product(v, w)?.0.abs()))
+}
+
+pub fn tetrahedron_volume(a: &Point3, b: &Point3, c: &Point3, d: &Point3) -> S {
+    let edges = [b, c, d].map(|p| p - a);
+    // a sixth of the parallelepiped on the same three edges
+    parallelepiped_volume(&edges[0], &edges[1], &edges[2]).unwrap() / 6.0
+}
+
+pub fn are_coplanar(a: &Point3, b: &Point3, c: &Point3, d: &Point3) -> bool {
+    are_coplanar_with(a, b, c, d, Tolerance::DEFAULT)
+}
+
+pub fn are_coplanar_with(
+    a: &Point3,
+    b: &Point3,
+    c: &Point3,
+    d: &Point3,
+    tolerance: Tolerance,
+) -> bool {
+    let edges = [b, c, d].map(|p| p - a);
+    let scale = edges.iter().map(|e| e.magnitude().0).product::<f64>();
+    if scale == 0.0 {
+        // repeated points always fit in some plane
+        return true;
+    }
+    // compare the volume against that of a box with the same edge lengths
+    let volume = parallelepiped_volume(&edges[0], &edges[1], &edges[2]).unwrap();
+    (volume / scale).approx_eq_with(&S(0.0), tolerance)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::MathError,
+        week5::{scalar::Scalar as S, vector::Vector},
+        week7::point::Point3,
+    };
+
+    use super::{
+        are_coplanar, parallelepiped_volume, parallelogram_area, tetrahedron_volume, triangle_area,
+    };
+
+    #[test]
+    fn triple_products() {
+        // stewart 12.4 example 5
+        let u = Vector(vec![1.0, 4.0, -7.0]);
+        let v = Vector(vec![2.0, -1.0, 4.0]);
+        let w = Vector(vec![0.0, -9.0, 18.0]);
+        assert_approx_eq!(u.scalar_triple_product(&v, &w).unwrap(), S(0.0));
+
+        let (i, j, k) = (
+            Vector(vec![1.0, 0.0, 0.0]),
+            Vector(vec![0.0, 1.0, 0.0]),
+            Vector(vec![0.0, 0.0, 1.0]),
+        );
+        assert_eq!(i.scalar_triple_product(&j, &k), Ok(S(1.0)));
+        assert_eq!(j.scalar_triple_product(&i, &k), Ok(S(-1.0)));
+
+        let expected = v.multiplied(u.dotted(&w)) - w.multiplied(u.dotted(&v));
+        assert_approx_eq!(u.vector_triple_product(&v, &w).unwrap(), expected);
+
+        let flat = Vector(vec![1.0, 2.0]);
+        for result in [
+            flat.scalar_triple_product(&v, &w),
+            u.scalar_triple_product(&flat, &w),
+            u.scalar_triple_product(&v, &flat),
+        ] {
+            assert_eq!(
+                result,
+                Err(MathError::UnsupportedDimension {
+                    expected: 3,
+                    found: 2
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn areas() {
+        // stewart 12.4 example 4
+        let p = Point3(1.0, 4.0, 6.0);
+        let q = Point3(-2.0, 5.0, -1.0);
+        let r = Point3(1.0, -1.0, 1.0);
+        assert_approx_eq!(parallelogram_area(&p, &q, &r), S(5.0 * 82.0_f64.sqrt()));
+        assert_approx_eq!(triangle_area(&p, &q, &r), S(5.0 * 82.0_f64.sqrt() / 2.0));
+
+        assert_approx_eq!(
+            triangle_area(
+                &Point3::ZERO,
+                &Point3(1.0, 0.0, 0.0),
+                &Point3(2.0, 0.0, 0.0)
+            ),
+            S(0.0)
+        );
+    }
+
+    #[test]
+    fn volumes() {
+        assert_approx_eq!(
+            parallelepiped_volume(
+                &Vector(vec![1.0, 2.0, 3.0]),
+                &Vector(vec![-1.0, 1.0, 2.0]),
+                &Vector(vec![2.0, 1.0, 4.0])
+            )
+            .unwrap(),
+            S(9.0)
+        );
+        assert_approx_eq!(
+            tetrahedron_volume(
+                &Point3::ZERO,
+                &Point3(1.0, 0.0, 0.0),
+                &Point3(0.0, 1.0, 0.0),
+                &Point3(0.0, 0.0, 1.0)
+            ),
+            S(1.0 / 6.0)
+        );
+    }
+
+    #[test]
+    fn coplanar() {
+        // stewart 12.4 exercise: (1, 0, 1), (2, 4, 6), (3, -1, 2), (6, 2, 8)
+        assert!(are_coplanar(
+            &Point3(1.0, 0.0, 1.0),
+            &Point3(2.0, 4.0, 6.0),
+            &Point3(3.0, -1.0, 2.0),
+            &Point3(6.0, 2.0, 8.0)
+        ));
+        assert!(!are_coplanar(
+            &Point3::ZERO,
+            &Point3(1.0, 0.0, 0.0),
+            &Point3(0.0, 1.0, 0.0),
+            &Point3(0.0, 0.0, 1e-3)
+        ));
+        assert!(are_coplanar(
+            &Point3::ZERO,
+            &Point3::ZERO,
+            &Point3(0.0, 1.0, 0.0),
+            &Point3(0.0, 0.0, 1.0)
+        ));
+    }
+}
@@ -1,8 +1,8 @@
 use std::f64::consts::E;
-use std::f64::EPSILON;
 use std::fmt::{Debug, Display};
 use std::ops::{Add, BitXor, Div, Mul, Sub};
 
+use crate::approx::{ApproxEq, Tolerance};
 use crate::week5::scalar::Scalar;
 
 #[derive(Clone, Debug)]
@@ -90,34 +90,57 @@ impl PartialEq for Expression {
     }
 }
 
+impl ApproxEq for Expression {
+    fn approx_eq_with(&self, other: &Self, tolerance: Tolerance) -> bool {
+        let eq = |lhs: &Expression, rhs: &Expression| lhs.approx_eq_with(rhs, tolerance);
+        match (self, other) {
+            (Self::Add(l0, l1), Self::Add(r0, r1))
+            | (Self::Multiply(l0, l1), Self::Multiply(r0, r1)) => {
+                (eq(l0, r0) && eq(l1, r1)) || (eq(l0, r1) && eq(l1, r0))
+            }
+            (Self::Subtract(l0, l1), Self::Subtract(r0, r1))
+            | (Self::Divide(l0, l1), Self::Divide(r0, r1))
+            | (Self::Exponentiate(l0, l1), Self::Exponentiate(r0, r1))
+            | (Self::Logarithm(l0, l1), Self::Logarithm(r0, r1)) => eq(l0, r0) && eq(l1, r1),
+            (Self::Variable(l0), Self::Variable(r0)) => l0 == r0,
+            (Self::Constant(l0), Self::Constant(r0)) => l0.approx_eq_with(r0, tolerance),
+            (
+                Self::DerivableFunctionExpression(fun1, inside1),
+                Self::DerivableFunctionExpression(fun2, inside2),
+            ) => fun1 == fun2 && eq(inside1, inside2),
+            (_, _) => false,
+        }
+    }
+}
+
 impl Expression {
     pub fn simplified(&self) -> Expression {
-        match {
-            match self {
-                Expression::Add(lhs, rhs) => lhs.simplified() + rhs.simplified(),
-                Expression::Subtract(lhs, rhs) => lhs.simplified() - rhs.simplified(),
-                Expression::Multiply(lhs, rhs) => lhs.simplified() * rhs.simplified(),
-                Expression::Divide(lhs, rhs) => lhs.simplified() / rhs.simplified(),
-                Expression::Exponentiate(lhs, rhs) => lhs.simplified() ^ rhs.simplified(),
-                Expression::Logarithm(lhs, rhs) => {
-                    Expression::Logarithm(Box::new(lhs.simplified()), Box::new(rhs.simplified()))
-                }
-                Expression::DerivableFunctionExpression(name, inside) => {
-                    Expression::DerivableFunctionExpression(
-                        name.clone(),
-                        Box::new(inside.simplified()),
-                    )
-                }
-                other => other.clone(),
+        let children_simplified = match self {
+            Expression::Add(lhs, rhs) => lhs.simplified() + rhs.simplified(),
+            Expression::Subtract(lhs, rhs) => lhs.simplified() - rhs.simplified(),
+            Expression::Multiply(lhs, rhs) => lhs.simplified() * rhs.simplified(),
+            Expression::Divide(lhs, rhs) => lhs.simplified() / rhs.simplified(),
+            Expression::Exponentiate(lhs, rhs) => lhs.simplified() ^ rhs.simplified(),
+            Expression::Logarithm(lhs, rhs) => {
+                Expression::Logarithm(Box::new(lhs.simplified()), Box::new(rhs.simplified()))
+            }
+            Expression::DerivableFunctionExpression(name, inside) => {
+                Expression::DerivableFunctionExpression(
+                    name.clone(),
+                    Box::new(inside.simplified()),
+                )
             }
-        } {
+            other => other.clone(),
+        };
+
+        match children_simplified {
             Expression::Add(
                 box Expression::Constant(Scalar(lhs)),
                 box Expression::Constant(Scalar(rhs)),
             ) => Expression::Constant(Scalar(lhs + rhs)),
             Expression::Add(box Expression::Constant(Scalar(zero)), v)
             | Expression::Add(v, box Expression::Constant(Scalar(zero)))
-                if zero.abs() <= EPSILON =>
+                if zero.abs() <= f64::EPSILON =>
             {
                 *v
             }
@@ -127,20 +150,20 @@ impl Expression {
                 box Expression::Constant(Scalar(rhs)),
             ) => Expression::Constant(Scalar(lhs - rhs)),
             Expression::Subtract(v, box Expression::Constant(Scalar(zero)))
-                if zero.abs() <= EPSILON =>
+                if zero.abs() <= f64::EPSILON =>
             {
                 *v
             }
 
             Expression::Multiply(box Expression::Constant(Scalar(zero)), _)
             | Expression::Multiply(_, box Expression::Constant(Scalar(zero)))
-                if zero.abs() <= EPSILON =>
+                if zero.abs() <= f64::EPSILON =>
             {
                 Expression::Constant(Scalar(0.))
             }
             Expression::Multiply(box Expression::Constant(Scalar(one)), other)
             | Expression::Multiply(other, box Expression::Constant(Scalar(one)))
-                if (1. - one).abs() <= EPSILON =>
+                if (1. - one).abs() <= f64::EPSILON =>
             {
                 *other
             }
@@ -150,7 +173,7 @@ impl Expression {
             ) => Expression::Constant(Scalar(lhs * rhs)),
             // Expression::Multiply(_, _) => todo!(),
             Expression::Divide(other, box Expression::Constant(Scalar(one)))
-                if (1. - one).abs() <= EPSILON =>
+                if (1. - one).abs() <= f64::EPSILON =>
             {
                 *other
             }
@@ -163,28 +186,28 @@ impl Expression {
             
             // 1^a
             Expression::Exponentiate(box Expression::Constant(Scalar(one)), _)
-                if (1. - one).abs() <= EPSILON =>
+                if (1. - one).abs() <= f64::EPSILON =>
             {
                 Expression::Constant(Scalar(1.))
             }
 
             // a^1
             Expression::Exponentiate(other, box Expression::Constant(Scalar(one)))
-                if (1. - one).abs() <= EPSILON =>
+                if (1. - one).abs() <= f64::EPSILON =>
             {
                 *other
             }
 
             // 0^a
             Expression::Exponentiate(box Expression::Constant(Scalar(zero)), _)
-                if zero.abs() <= EPSILON =>
+                if zero.abs() <= f64::EPSILON =>
             {
                 Expression::Constant(Scalar(0.))
             }
 
             // a^0
             Expression::Exponentiate(_, box Expression::Constant(Scalar(zero)))
-                if zero.abs() <= EPSILON =>
+                if zero.abs() <= f64::EPSILON =>
             {
                 Expression::Constant(Scalar(1.))
             }
@@ -214,7 +237,7 @@ impl Display for Expression {
             Expression::Subtract(lhs, rhs) => write!(f, "({} - {})", lhs, rhs),
             Expression::Multiply(box Self::Constant(Scalar(neg_one)), other)
             | Expression::Multiply(other, box Self::Constant(Scalar(neg_one)))
-                if (neg_one + 1.).abs() <= EPSILON =>
+                if (neg_one + 1.).abs() <= f64::EPSILON =>
             {
                 write!(f, "-({})", other)
             }
@@ -222,7 +245,7 @@ impl Display for Expression {
             Expression::Divide(lhs, rhs) => write!(f, "({} / {})", lhs, rhs),
             Expression::Exponentiate(base, power) => write!(f, "({} ^ {})", base, power),
             Expression::Logarithm(box Expression::Constant(Scalar(e)), inside)
-                if E - e <= EPSILON =>
+                if E - e <= f64::EPSILON =>
             {
                 write!(f, "ln({})", inside)
             }
@@ -320,6 +343,17 @@ mod test {
         assert_eq!((x1 ^ one).simplified(), x2);
     }
 
+    #[test]
+    fn approx_eq() {
+        let x = Expression::Variable('x');
+
+        assert_approx_eq!(
+            ((scalar(0.1) + scalar(0.2)) * x.clone()).simplified(),
+            x.clone() * scalar(0.3)
+        );
+        assert_approx_ne!(x.clone() - scalar(0.3), scalar(0.3) - x);
+    }
+
     #[test]
     fn display() {
         let ex: Expression = Expression::Constant(Scalar(1.))
@@ -2,6 +2,7 @@ use std::{collections::HashMap, ops::Range};
 
 use crate::{week5::scalar::Scalar, week8::expression::Expression};
 
+#[allow(dead_code)]
 trait LevelCurves {
     // fn level_curves(&self, z_values: &T) where T: Iterator<f64> {
 
@@ -60,7 +61,7 @@ impl Expression {
         let range = {
             let mut sorted = series.clone();
             sorted.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap());
-            sorted.first().unwrap().1.clone()..sorted.last().unwrap().1.clone()
+            sorted.first().unwrap().1..sorted.last().unwrap().1
         };
 
         let root_drawing_area = BitMapBackend::new(save_path, (w, h)).into_drawing_area();